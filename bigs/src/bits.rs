@@ -0,0 +1,190 @@
+//! Bit-packed binary vectors.
+
+use crate::matrix::WORD_SIZE;
+use rand::Rng;
+use std::ops::BitXorAssign;
+
+/// A bit-packed binary vector.
+///
+/// The bit at position i is the bit `i % 64` of the word `i / 64`.
+/// The unused bits of the last word are always 0,
+/// so words can be combined with the rows of a [`DenseMatrix`](crate::matrix::DenseMatrix)
+/// of the same number of columns.
+///
+/// # Example
+///
+/// ```
+/// use bigs::bits::BitVec;
+///
+/// let mut bits = BitVec::zeros(70);
+/// bits.set(3, true);
+/// bits.flip(68);
+///
+/// assert!(bits.get(3));
+/// assert!(bits.get(68));
+/// assert_eq!(bits.weight(), 2);
+/// assert_eq!(bits.ones().collect::<Vec<_>>(), vec![3, 68]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Default)]
+pub struct BitVec {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl BitVec {
+    /// Creates a vector of the given length filled with zeros.
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            len,
+            words: vec![0; len.div_ceil(WORD_SIZE)],
+        }
+    }
+
+    /// Creates a vector of the given length with independent uniform bits.
+    pub fn random_with<R: Rng>(len: usize, rng: &mut R) -> Self {
+        let mut bits = Self {
+            len,
+            words: (0..len.div_ceil(WORD_SIZE)).map(|_| rng.gen()).collect(),
+        };
+        bits.clear_unused_bits();
+        bits
+    }
+
+    pub(crate) fn from_words(len: usize, words: Vec<u64>) -> Self {
+        debug_assert_eq!(words.len(), len.div_ceil(WORD_SIZE));
+        let mut bits = Self { len, words };
+        bits.clear_unused_bits();
+        bits
+    }
+
+    /// Returns the number of bits in the vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the vector has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bound.
+    pub fn get(&self, position: usize) -> bool {
+        self.check_bound(position);
+        self.words[position / WORD_SIZE] >> (position % WORD_SIZE) & 1 == 1
+    }
+
+    /// Sets the bit at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bound.
+    pub fn set(&mut self, position: usize, value: bool) {
+        self.check_bound(position);
+        let mask = 1 << (position % WORD_SIZE);
+        if value {
+            self.words[position / WORD_SIZE] |= mask;
+        } else {
+            self.words[position / WORD_SIZE] &= !mask;
+        }
+    }
+
+    /// Flips the bit at the given position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bound.
+    pub fn flip(&mut self, position: usize) {
+        self.check_bound(position);
+        self.words[position / WORD_SIZE] ^= 1 << (position % WORD_SIZE);
+    }
+
+    /// Returns the number of ones in the vector.
+    pub fn weight(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Checks if all bits are 0.
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Returns an iterator over all bits in order.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |position| self.get(position))
+    }
+
+    /// Returns an iterator over the positions of the ones in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    None
+                } else {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    Some(index * WORD_SIZE + bit)
+                }
+            })
+        })
+    }
+
+    /// Returns the words storing the vector.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    fn clear_unused_bits(&mut self) {
+        if self.len % WORD_SIZE != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << (self.len % WORD_SIZE)) - 1;
+            }
+        }
+    }
+
+    fn check_bound(&self, position: usize) {
+        assert!(
+            position < self.len,
+            "position {} is out of bound for a vector of length {}",
+            position,
+            self.len
+        );
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = Self::default();
+        for bit in iter {
+            if bits.len % WORD_SIZE == 0 {
+                bits.words.push(0);
+            }
+            bits.len += 1;
+            if bit {
+                bits.set(bits.len - 1, true);
+            }
+        }
+        bits
+    }
+}
+
+impl BitXorAssign<&BitVec> for BitVec {
+    /// Adds the other vector to this one over GF(2).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    fn bitxor_assign(&mut self, other: &BitVec) {
+        assert_eq!(self.len, other.len, "vectors have different lengths");
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word ^= other;
+        }
+    }
+}
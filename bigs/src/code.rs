@@ -0,0 +1,149 @@
+//! Binary linear codes defined by graphs.
+//!
+//! A graph defines the LDPC code whose codewords are the assignments
+//! of the variables such that each constraint has an even number of neighbors set to 1.
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::bits::BitVec;
+//! use bigs::code::Code;
+//! use bigs::Sampler;
+//! use rand::thread_rng;
+//!
+//! let sampler = Sampler::builder()
+//!     .number_of_variables(12)
+//!     .number_of_constraints(6)
+//!     .variable_degree(3)
+//!     .constraint_degree(6)
+//!     .build()
+//!     .unwrap();
+//!
+//! let code = Code::new(sampler.sample_with(&mut thread_rng()));
+//! let message = BitVec::random_with(code.dimension(), &mut thread_rng());
+//! let codeword = code.encode(&message);
+//!
+//! assert_eq!(codeword.len(), 12);
+//! assert!(code.is_codeword(&codeword));
+//! ```
+
+use crate::bits::BitVec;
+use crate::graph::Graph;
+use crate::matrix::{DenseMatrix, WORD_SIZE};
+
+/// The binary linear code defined by a graph together with a systematic encoder.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Code {
+    graph: Graph,
+    generator_matrix: DenseMatrix,
+    information_set: Vec<usize>,
+}
+
+impl Code {
+    /// Creates the code defined by the given graph.
+    ///
+    /// This computes a generator matrix in systematic form,
+    /// which takes a time cubic in the size of the graph.
+    pub fn new(graph: Graph) -> Self {
+        let echelon = graph.parity_check_matrix().row_echelon_form();
+        let information_set = echelon.free_columns();
+        let generator_matrix = echelon.null_space();
+        Self {
+            graph,
+            generator_matrix,
+            information_set,
+        }
+    }
+
+    /// Returns the graph defining the code.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the length of the codewords, that is, the number of variables.
+    pub fn length(&self) -> usize {
+        self.graph.number_of_variables()
+    }
+
+    /// Returns the dimension of the code, that is, the length of the messages.
+    pub fn dimension(&self) -> usize {
+        self.information_set.len()
+    }
+
+    /// Returns the rate of the code, that is, the dimension divided by the length,
+    /// or 0 if the length is 0.
+    pub fn rate(&self) -> f64 {
+        if self.length() == 0 {
+            0.0
+        } else {
+            self.dimension() as f64 / self.length() as f64
+        }
+    }
+
+    /// Returns the generator matrix of the code.
+    ///
+    /// It has one row per message bit and one column per variable
+    /// and its rows are a basis of the codewords.
+    /// It is in systematic form: the column of the i-th variable of the
+    /// [`information_set`](Code::information_set) is 1 in row i and 0 elsewhere.
+    pub fn generator_matrix(&self) -> &DenseMatrix {
+        &self.generator_matrix
+    }
+
+    /// Returns the variables holding the message bits in a codeword in increasing order.
+    ///
+    /// Bit i of a message is the value of the i-th variable of the information set
+    /// in its codeword.
+    pub fn information_set(&self) -> &[usize] {
+        &self.information_set
+    }
+
+    /// Returns the codeword of the given message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the message is not the dimension of the code.
+    pub fn encode(&self, message: &BitVec) -> BitVec {
+        assert_eq!(
+            message.len(),
+            self.dimension(),
+            "the message must have one bit per dimension of the code"
+        );
+        let mut words = vec![0; self.length().div_ceil(WORD_SIZE)];
+        for row in message.ones() {
+            for (word, row_word) in words.iter_mut().zip(self.generator_matrix.row_words(row)) {
+                *word ^= row_word;
+            }
+        }
+        BitVec::from_words(self.length(), words)
+    }
+
+    /// Returns the message of the given codeword.
+    ///
+    /// This only reads the bits of the information set,
+    /// so it is the inverse of [`encode`](Code::encode) on codewords.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the word is not the length of the code.
+    pub fn extract_message(&self, codeword: &BitVec) -> BitVec {
+        assert_eq!(
+            codeword.len(),
+            self.length(),
+            "the codeword must have one bit per variable"
+        );
+        self.information_set
+            .iter()
+            .map(|&variable| codeword.get(variable))
+            .collect()
+    }
+
+    /// Checks if the given word satisfies all parity checks of the code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the word is not the length of the code.
+    pub fn is_codeword(&self, word: &BitVec) -> bool {
+        self.graph.syndrome(word).is_zero()
+    }
+}
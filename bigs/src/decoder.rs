@@ -0,0 +1,215 @@
+//! Decoders for the codes defined by graphs.
+//!
+//! All decoders implement the [`Decoder`](Decoder) trait.
+//! They take the log-likelihood ratios (LLRs) of the variables given by a channel
+//! and return a guess of the transmitted codeword.
+//! The LLR of a variable is `ln(P(0) / P(1))`,
+//! so a positive LLR means that the variable is more likely to be 0.
+//! A variable whose final LLR is exactly 0, such as an erasure that was not recovered,
+//! carries no information, so decoders report it as undetermined.
+//! The functions [`bsc_llrs`](bsc_llrs) and [`awgn_llrs`](awgn_llrs)
+//! compute the LLRs for the usual channels.
+//!
+//! The available decoders are
+//! - [belief propagation](BeliefPropagation) with the sum-product or min-sum rules,
+//! - [Gallager's bit flipping](BitFlipping),
+//! - [Sipser and Spielman's flip decoder](SipserSpielman) for expander codes,
+//! - the [peeling decoder](Peeling) for the erasure channel.
+//!
+//! Bit-flipping decoders only use the hard decisions given by the signs of the LLRs,
+//! so they do not recover erasures:
+//! a variable with an LLR of 0 stays undetermined unless the decoder flips it.
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::bits::BitVec;
+//! use bigs::decoder::{bsc_llrs, BeliefPropagation, Decoder};
+//! use bigs::Sampler;
+//! use rand::thread_rng;
+//!
+//! let sampler = Sampler::builder()
+//!     .number_of_variables(100)
+//!     .number_of_constraints(50)
+//!     .variable_degree(3)
+//!     .constraint_degree(6)
+//!     .build()
+//!     .unwrap();
+//! let graph = sampler.sample_with(&mut thread_rng());
+//!
+//! // The all-zero codeword with a single error.
+//! let mut received = BitVec::zeros(100);
+//! received.flip(42);
+//!
+//! let mut decoder = BeliefPropagation::new(&graph);
+//! let decoding = decoder.decode(&bsc_llrs(&received, 0.01));
+//! assert!(decoding.converged);
+//! assert!(decoding.word.is_zero());
+//! ```
+
+mod belief_propagation;
+mod bit_flipping;
+mod peeling;
+
+pub use belief_propagation::{BeliefPropagation, UpdateRule};
+pub use bit_flipping::{BitFlipping, SipserSpielman};
+pub use peeling::Peeling;
+
+use crate::bits::BitVec;
+use crate::graph::Graph;
+use crate::matrix::SparseMatrix;
+
+/// A decoder for the code defined by a graph.
+pub trait Decoder {
+    /// Decodes a word from the LLRs of its variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not one LLR per variable.
+    fn decode(&mut self, llrs: &[f64]) -> Decoding;
+}
+
+/// The output of a decoder.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Decoding {
+    /// The decoded word.
+    pub word: BitVec,
+    /// The number of iterations done by the decoder.
+    pub iterations: usize,
+    /// True if the decoded word is a codeword and no variable is undetermined.
+    pub converged: bool,
+    /// The variables whose value the decoder could not determine.
+    ///
+    /// Their value in the decoded word is a guess.
+    pub undetermined: BitVec,
+}
+
+/// Returns the LLRs of a word received through a binary symmetric channel
+/// with the given crossover probability.
+///
+/// # Example
+///
+/// ```
+/// use bigs::bits::BitVec;
+/// use bigs::decoder::bsc_llrs;
+///
+/// let received: BitVec = vec![false, true].into_iter().collect();
+/// let llrs = bsc_llrs(&received, 0.1);
+///
+/// assert!((llrs[0] - 9f64.ln()).abs() < 1e-12);
+/// assert!((llrs[1] + 9f64.ln()).abs() < 1e-12);
+/// ```
+pub fn bsc_llrs(received: &BitVec, crossover_probability: f64) -> Vec<f64> {
+    let llr = ((1.0 - crossover_probability) / crossover_probability).ln();
+    received
+        .iter()
+        .map(|bit| if bit { -llr } else { llr })
+        .collect()
+}
+
+/// Returns the LLRs of a signal received through an additive white Gaussian noise channel
+/// with the given noise standard deviation.
+///
+/// The transmitted signal is assumed to be BPSK modulated,
+/// that is, 0 is sent as +1 and 1 is sent as -1.
+pub fn awgn_llrs(received: &[f64], noise_standard_deviation: f64) -> Vec<f64> {
+    let scale = 2.0 / (noise_standard_deviation * noise_standard_deviation);
+    received.iter().map(|signal| scale * signal).collect()
+}
+
+/// The Tanner graph of a code laid out for message passing.
+///
+/// Edges are numbered as the ones of the sparse parity-check matrix,
+/// so the edges of each constraint are contiguous.
+#[derive(Debug, Clone)]
+pub(crate) struct TannerGraph {
+    parity_check_matrix: SparseMatrix,
+    edge_constraints: Vec<usize>,
+    variable_offsets: Vec<usize>,
+    variable_edges: Vec<usize>,
+}
+
+impl TannerGraph {
+    pub(crate) fn new(graph: &Graph) -> Self {
+        let parity_check_matrix = graph.sparse_parity_check_matrix();
+        let edge_constraints = (0..parity_check_matrix.number_of_rows())
+            .flat_map(|constraint| {
+                std::iter::repeat(constraint).take(parity_check_matrix.row(constraint).len())
+            })
+            .collect();
+        let mut variable_offsets = vec![0; graph.number_of_variables() + 1];
+        for &variable in parity_check_matrix.column_indices() {
+            variable_offsets[variable + 1] += 1;
+        }
+        for variable in 0..graph.number_of_variables() {
+            variable_offsets[variable + 1] += variable_offsets[variable];
+        }
+        let mut positions = variable_offsets.clone();
+        let mut variable_edges = vec![0; parity_check_matrix.number_of_nonzeros()];
+        for (edge, &variable) in parity_check_matrix.column_indices().iter().enumerate() {
+            variable_edges[positions[variable]] = edge;
+            positions[variable] += 1;
+        }
+        Self {
+            parity_check_matrix,
+            edge_constraints,
+            variable_offsets,
+            variable_edges,
+        }
+    }
+
+    pub(crate) fn number_of_variables(&self) -> usize {
+        self.parity_check_matrix.number_of_columns()
+    }
+
+    pub(crate) fn number_of_constraints(&self) -> usize {
+        self.parity_check_matrix.number_of_rows()
+    }
+
+    pub(crate) fn number_of_edges(&self) -> usize {
+        self.parity_check_matrix.number_of_nonzeros()
+    }
+
+    /// The range of the edges of the given constraint.
+    pub(crate) fn constraint_edges(&self, constraint: usize) -> std::ops::Range<usize> {
+        let offsets = self.parity_check_matrix.row_offsets();
+        offsets[constraint]..offsets[constraint + 1]
+    }
+
+    /// The edges of the given variable.
+    pub(crate) fn variable_edges(&self, variable: usize) -> &[usize] {
+        &self.variable_edges[self.variable_offsets[variable]..self.variable_offsets[variable + 1]]
+    }
+
+    pub(crate) fn edge_variable(&self, edge: usize) -> usize {
+        self.parity_check_matrix.column_indices()[edge]
+    }
+
+    pub(crate) fn edge_constraint(&self, edge: usize) -> usize {
+        self.edge_constraints[edge]
+    }
+
+    pub(crate) fn syndrome(&self, word: &BitVec) -> BitVec {
+        self.parity_check_matrix.syndrome(word)
+    }
+
+    pub(crate) fn check_llrs(&self, llrs: &[f64]) {
+        assert_eq!(
+            llrs.len(),
+            self.number_of_variables(),
+            "there must be one LLR per variable"
+        );
+    }
+
+    /// The word where each variable is 1 if its LLR is negative.
+    pub(crate) fn hard_decisions(&self, llrs: &[f64]) -> BitVec {
+        self.check_llrs(llrs);
+        llrs.iter().map(|&llr| llr < 0.0).collect()
+    }
+
+    /// The word where each variable is 1 if its LLR is 0.
+    pub(crate) fn erasures(&self, llrs: &[f64]) -> BitVec {
+        self.check_llrs(llrs);
+        llrs.iter().map(|&llr| llr == 0.0).collect()
+    }
+}
@@ -0,0 +1,208 @@
+use super::{Decoder, Decoding, TannerGraph};
+use crate::bits::BitVec;
+use crate::graph::Graph;
+
+// LLRs are clamped to this magnitude so that infinite LLRs,
+// such as the ones of unerased bits on an erasure channel, do not create NaNs.
+const LLR_LIMIT: f64 = 1e3;
+// The largest magnitude of the product of tanh in the check update of sum-product,
+// which keeps the messages finite.
+const TANH_LIMIT: f64 = 1.0 - 1e-12;
+
+/// The rule used by belief propagation to update the messages sent by constraints.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum UpdateRule {
+    /// The exact sum-product (tanh) rule.
+    SumProduct,
+    /// The min-sum approximation where messages are multiplied by the given
+    /// normalization factor, usually between 0.7 and 0.9.
+    /// A factor of 1 gives the plain min-sum rule.
+    NormalizedMinSum(f64),
+}
+
+/// A belief-propagation (message-passing) decoder.
+///
+/// At each iteration, every constraint sends a message to each of its neighbors
+/// computed from the messages of its other neighbors using the [`UpdateRule`](UpdateRule),
+/// then each variable sends to each of its neighbors its channel LLR plus the messages
+/// of its other neighbors.
+/// The decoded word is the hard decision on the channel LLR plus all incoming messages.
+///
+/// By default, the decoder uses the sum-product rule, does at most 50 iterations
+/// and stops as soon as the decoded word is a codeword.
+///
+/// # Example
+///
+/// ```
+/// use bigs::decoder::{BeliefPropagation, Decoder, UpdateRule};
+/// use bigs::graph::Graph;
+///
+/// // A single parity check on 3 variables.
+/// let graph = Graph::complete_graph(3, 1);
+///
+/// let mut decoder = BeliefPropagation::new(&graph);
+/// decoder
+///     .update_rule(UpdateRule::NormalizedMinSum(0.8))
+///     .max_iterations(10);
+///
+/// // The last variable is unreliable and gets corrected.
+/// let decoding = decoder.decode(&[2.0, -3.0, 0.5]);
+/// assert!(decoding.converged);
+/// assert_eq!(decoding.iterations, 1);
+/// assert_eq!(decoding.word.iter().collect::<Vec<_>>(), vec![false, true, true]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BeliefPropagation {
+    graph: TannerGraph,
+    update_rule: UpdateRule,
+    max_iterations: usize,
+    early_stopping: bool,
+    variable_messages: Vec<f64>,
+    constraint_messages: Vec<f64>,
+    prefix_products: Vec<f64>,
+}
+
+impl BeliefPropagation {
+    /// Creates a decoder for the code defined by the given graph.
+    pub fn new(graph: &Graph) -> Self {
+        let graph = TannerGraph::new(graph);
+        let number_of_edges = graph.number_of_edges();
+        Self {
+            graph,
+            update_rule: UpdateRule::SumProduct,
+            max_iterations: 50,
+            early_stopping: true,
+            variable_messages: vec![0.0; number_of_edges],
+            constraint_messages: vec![0.0; number_of_edges],
+            prefix_products: vec![0.0; number_of_edges],
+        }
+    }
+
+    /// Fixes the update rule of the constraints. Default is sum-product.
+    pub fn update_rule(&mut self, rule: UpdateRule) -> &mut Self {
+        self.update_rule = rule;
+        self
+    }
+
+    /// Fixes the maximum number of iterations. Default is 50.
+    pub fn max_iterations(&mut self, iterations: usize) -> &mut Self {
+        self.max_iterations = iterations;
+        self
+    }
+
+    /// Fixes if the decoder stops as soon as the decoded word is a codeword
+    /// without undetermined variables.
+    /// Default is true.
+    ///
+    /// Without early stopping, the decoder always does the maximum number of iterations.
+    pub fn early_stopping(&mut self, enabled: bool) -> &mut Self {
+        self.early_stopping = enabled;
+        self
+    }
+
+    fn update_constraints(&mut self) {
+        for constraint in 0..self.graph.number_of_constraints() {
+            let edges = self.graph.constraint_edges(constraint);
+            match self.update_rule {
+                UpdateRule::SumProduct => self.sum_product(edges),
+                UpdateRule::NormalizedMinSum(normalization) => {
+                    self.normalized_min_sum(edges, normalization)
+                }
+            }
+        }
+    }
+
+    fn sum_product(&mut self, edges: std::ops::Range<usize>) {
+        let mut product = 1.0;
+        for edge in edges.clone() {
+            self.prefix_products[edge] = product;
+            product *= (self.variable_messages[edge] / 2.0).tanh();
+        }
+        let mut suffix_product = 1.0;
+        for edge in edges.rev() {
+            let product =
+                (self.prefix_products[edge] * suffix_product).clamp(-TANH_LIMIT, TANH_LIMIT);
+            suffix_product *= (self.variable_messages[edge] / 2.0).tanh();
+            self.constraint_messages[edge] = 2.0 * product.atanh();
+        }
+    }
+
+    fn normalized_min_sum(&mut self, edges: std::ops::Range<usize>, normalization: f64) {
+        let mut is_negative = false;
+        let mut minimum = f64::INFINITY;
+        let mut second_minimum = f64::INFINITY;
+        let mut minimum_edge = edges.start;
+        for edge in edges.clone() {
+            let message = self.variable_messages[edge];
+            is_negative ^= message < 0.0;
+            let magnitude = message.abs();
+            if magnitude < minimum {
+                second_minimum = minimum;
+                minimum = magnitude;
+                minimum_edge = edge;
+            } else if magnitude < second_minimum {
+                second_minimum = magnitude;
+            }
+        }
+        for edge in edges {
+            let magnitude = if edge == minimum_edge {
+                second_minimum
+            } else {
+                minimum
+            };
+            let is_negative = is_negative ^ (self.variable_messages[edge] < 0.0);
+            let message = normalization * magnitude.min(LLR_LIMIT);
+            self.constraint_messages[edge] = if is_negative { -message } else { message };
+        }
+    }
+
+    // Sends the messages of the variables and returns the decoded word
+    // together with the variables whose total LLR is 0.
+    fn update_variables(&mut self, llrs: &[f64]) -> (BitVec, BitVec) {
+        let mut word = BitVec::zeros(llrs.len());
+        let mut undetermined = BitVec::zeros(llrs.len());
+        for (variable, &llr) in llrs.iter().enumerate() {
+            let edges = self.graph.variable_edges(variable);
+            let total = edges
+                .iter()
+                .fold(llr, |total, &edge| total + self.constraint_messages[edge]);
+            for &edge in edges {
+                self.variable_messages[edge] = total - self.constraint_messages[edge];
+            }
+            if total < 0.0 {
+                word.set(variable, true);
+            } else if total == 0.0 {
+                undetermined.set(variable, true);
+            }
+        }
+        (word, undetermined)
+    }
+}
+
+impl Decoder for BeliefPropagation {
+    fn decode(&mut self, llrs: &[f64]) -> Decoding {
+        self.graph.check_llrs(llrs);
+        let llrs: Vec<f64> = llrs
+            .iter()
+            .map(|llr| llr.clamp(-LLR_LIMIT, LLR_LIMIT))
+            .collect();
+        self.constraint_messages
+            .iter_mut()
+            .for_each(|message| *message = 0.0);
+        let (mut word, mut undetermined) = self.update_variables(&llrs);
+        let mut converged = undetermined.is_zero() && self.graph.syndrome(&word).is_zero();
+        let mut iterations = 0;
+        while iterations < self.max_iterations && !(converged && self.early_stopping) {
+            self.update_constraints();
+            (word, undetermined) = self.update_variables(&llrs);
+            converged = undetermined.is_zero() && self.graph.syndrome(&word).is_zero();
+            iterations += 1;
+        }
+        Decoding {
+            word,
+            iterations,
+            converged,
+            undetermined,
+        }
+    }
+}
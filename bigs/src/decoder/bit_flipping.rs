@@ -0,0 +1,185 @@
+use super::{Decoder, Decoding, TannerGraph};
+use crate::graph::Graph;
+use std::collections::BinaryHeap;
+
+/// Gallager's bit-flipping decoder.
+///
+/// At each iteration, the decoder counts the unsatisfied constraints of each variable
+/// and flips all the variables with the largest count.
+/// It stops as soon as the word is a codeword or after the maximum number of iterations,
+/// 100 by default.
+///
+/// # Example
+///
+/// ```
+/// use bigs::decoder::{BitFlipping, Decoder};
+/// use bigs::graph::Graph;
+///
+/// // The repetition code of length 3 with a parity check on each pair of variables.
+/// let graph = Graph::from_variable_neighbors(vec![vec![0, 2], vec![0, 1], vec![1, 2]]);
+///
+/// let decoding = BitFlipping::new(&graph).decode(&[1.0, -1.0, 1.0]);
+/// assert!(decoding.converged);
+/// assert_eq!(decoding.iterations, 1);
+/// assert!(decoding.word.is_zero());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitFlipping {
+    graph: TannerGraph,
+    max_iterations: usize,
+}
+
+impl BitFlipping {
+    /// Creates a decoder for the code defined by the given graph.
+    pub fn new(graph: &Graph) -> Self {
+        Self {
+            graph: TannerGraph::new(graph),
+            max_iterations: 100,
+        }
+    }
+
+    /// Fixes the maximum number of iterations. Default is 100.
+    pub fn max_iterations(&mut self, iterations: usize) -> &mut Self {
+        self.max_iterations = iterations;
+        self
+    }
+}
+
+impl Decoder for BitFlipping {
+    fn decode(&mut self, llrs: &[f64]) -> Decoding {
+        let mut word = self.graph.hard_decisions(llrs);
+        let mut undetermined = self.graph.erasures(llrs);
+        let mut syndrome = self.graph.syndrome(&word);
+        let mut iterations = 0;
+        while iterations < self.max_iterations && !syndrome.is_zero() {
+            let unsatisfied_counts: Vec<usize> = (0..self.graph.number_of_variables())
+                .map(|variable| {
+                    self.graph
+                        .variable_edges(variable)
+                        .iter()
+                        .filter(|&&edge| syndrome.get(self.graph.edge_constraint(edge)))
+                        .count()
+                })
+                .collect();
+            let max_count = unsatisfied_counts.iter().cloned().max().unwrap_or(0);
+            for (variable, &count) in unsatisfied_counts.iter().enumerate() {
+                if count == max_count {
+                    word.flip(variable);
+                    undetermined.set(variable, false);
+                }
+            }
+            syndrome = self.graph.syndrome(&word);
+            iterations += 1;
+        }
+        Decoding {
+            word,
+            iterations,
+            converged: syndrome.is_zero() && undetermined.is_zero(),
+            undetermined,
+        }
+    }
+}
+
+/// Sipser and Spielman's sequential flip decoder for expander codes.
+///
+/// While there is a variable with more unsatisfied than satisfied constraints,
+/// the decoder flips one of them, choosing the one whose flip
+/// decreases the most the number of unsatisfied constraints.
+/// Each flip decreases the number of unsatisfied constraints,
+/// so the decoder stops after at most as many flips as there are constraints,
+/// either on a codeword or on a word where no variable can be flipped.
+/// The number of iterations of the decoding is the number of flips.
+///
+/// On a graph with good enough vertex expansion,
+/// this corrects a constant fraction of errors.
+///
+/// # Example
+///
+/// ```
+/// use bigs::decoder::{Decoder, SipserSpielman};
+/// use bigs::graph::Graph;
+///
+/// // The repetition code of length 3 with a parity check on each pair of variables.
+/// let graph = Graph::from_variable_neighbors(vec![vec![0, 2], vec![0, 1], vec![1, 2]]);
+///
+/// let decoding = SipserSpielman::new(&graph).decode(&[1.0, -1.0, 1.0]);
+/// assert!(decoding.converged);
+/// assert_eq!(decoding.iterations, 1);
+/// assert!(decoding.word.is_zero());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SipserSpielman {
+    graph: TannerGraph,
+}
+
+impl SipserSpielman {
+    /// Creates a decoder for the code defined by the given graph.
+    pub fn new(graph: &Graph) -> Self {
+        Self {
+            graph: TannerGraph::new(graph),
+        }
+    }
+
+    // The decrease in the number of unsatisfied constraints when flipping the variable.
+    fn gain(&self, variable: usize, unsatisfied_count: usize) -> isize {
+        2 * unsatisfied_count as isize - self.graph.variable_edges(variable).len() as isize
+    }
+}
+
+impl Decoder for SipserSpielman {
+    fn decode(&mut self, llrs: &[f64]) -> Decoding {
+        let mut word = self.graph.hard_decisions(llrs);
+        let mut undetermined = self.graph.erasures(llrs);
+        let mut syndrome = self.graph.syndrome(&word);
+        let mut unsatisfied_counts: Vec<usize> = (0..self.graph.number_of_variables())
+            .map(|variable| {
+                self.graph
+                    .variable_edges(variable)
+                    .iter()
+                    .filter(|&&edge| syndrome.get(self.graph.edge_constraint(edge)))
+                    .count()
+            })
+            .collect();
+        let mut candidates: BinaryHeap<(isize, usize)> = (0..self.graph.number_of_variables())
+            .map(|variable| (self.gain(variable, unsatisfied_counts[variable]), variable))
+            .filter(|&(gain, _)| gain > 0)
+            .collect();
+        let mut flips = 0;
+        while let Some((gain, variable)) = candidates.pop() {
+            // The gains of the candidates are updated lazily.
+            let current_gain = self.gain(variable, unsatisfied_counts[variable]);
+            if gain != current_gain {
+                if current_gain > 0 {
+                    candidates.push((current_gain, variable));
+                }
+                continue;
+            }
+            word.flip(variable);
+            undetermined.set(variable, false);
+            flips += 1;
+            for &edge in self.graph.variable_edges(variable) {
+                let constraint = self.graph.edge_constraint(edge);
+                syndrome.flip(constraint);
+                let is_unsatisfied = syndrome.get(constraint);
+                for neighbor_edge in self.graph.constraint_edges(constraint) {
+                    let neighbor = self.graph.edge_variable(neighbor_edge);
+                    if is_unsatisfied {
+                        unsatisfied_counts[neighbor] += 1;
+                        let gain = self.gain(neighbor, unsatisfied_counts[neighbor]);
+                        if gain > 0 {
+                            candidates.push((gain, neighbor));
+                        }
+                    } else {
+                        unsatisfied_counts[neighbor] -= 1;
+                    }
+                }
+            }
+        }
+        Decoding {
+            word,
+            iterations: flips,
+            converged: syndrome.is_zero() && undetermined.is_zero(),
+            undetermined,
+        }
+    }
+}
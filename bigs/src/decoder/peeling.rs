@@ -0,0 +1,147 @@
+use super::{Decoder, Decoding, TannerGraph};
+use crate::bits::BitVec;
+use crate::graph::Graph;
+
+/// The peeling decoder for the erasure channel.
+///
+/// Variables with an LLR of 0 are erased and the others are known from the sign of their LLR.
+/// While a constraint has a single erased neighbor,
+/// the decoder recovers it as the parity of the other neighbors of the constraint.
+/// It stops when the remaining erased variables form a stopping set,
+/// that is, when each constraint has either none or at least 2 erased neighbors.
+/// Unrecovered variables are decoded as 0 and reported as undetermined.
+/// The number of iterations of the decoding is the number of recovered variables.
+///
+/// This is equivalent to belief propagation on the erasure channel, but much faster.
+///
+/// # Example
+///
+/// ```
+/// use bigs::decoder::{Decoder, Peeling};
+/// use bigs::graph::Graph;
+///
+/// // A single parity check on 3 variables.
+/// let graph = Graph::complete_graph(3, 1);
+///
+/// let decoding = Peeling::new(&graph).decode(&[f64::INFINITY, 0.0, f64::NEG_INFINITY]);
+/// assert!(decoding.converged);
+/// assert_eq!(decoding.iterations, 1);
+/// assert_eq!(decoding.word.iter().collect::<Vec<_>>(), vec![false, true, true]);
+/// assert!(decoding.undetermined.is_zero());
+///
+/// let decoding = Peeling::new(&graph).decode(&[f64::INFINITY, 0.0, 0.0]);
+/// assert!(!decoding.converged);
+/// assert_eq!(decoding.undetermined.iter().collect::<Vec<_>>(), vec![false, true, true]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Peeling {
+    graph: TannerGraph,
+}
+
+impl Peeling {
+    /// Creates a decoder for the code defined by the given graph.
+    pub fn new(graph: &Graph) -> Self {
+        Self {
+            graph: TannerGraph::new(graph),
+        }
+    }
+
+    // Recovers the erased variables of the word and returns the number of recovered variables.
+    // The erased variables must be 0 in the word.
+    fn peel(&self, word: &mut BitVec, erased: &mut BitVec) -> usize {
+        let graph = &self.graph;
+        let mut erased_counts = vec![0; graph.number_of_constraints()];
+        let mut parities = graph.syndrome(word);
+        for variable in erased.ones() {
+            for &edge in graph.variable_edges(variable) {
+                erased_counts[graph.edge_constraint(edge)] += 1;
+            }
+        }
+        let mut resolvable: Vec<usize> = (0..graph.number_of_constraints())
+            .filter(|&constraint| erased_counts[constraint] == 1)
+            .collect();
+        let mut recovered = 0;
+        while let Some(constraint) = resolvable.pop() {
+            // The last erased neighbor may have been recovered by another constraint.
+            if erased_counts[constraint] != 1 {
+                continue;
+            }
+            let variable = graph
+                .constraint_edges(constraint)
+                .map(|edge| graph.edge_variable(edge))
+                .find(|&variable| erased.get(variable))
+                .expect("a constraint with an erased neighbor");
+            let value = parities.get(constraint);
+            erased.set(variable, false);
+            word.set(variable, value);
+            recovered += 1;
+            for &edge in graph.variable_edges(variable) {
+                let neighbor = graph.edge_constraint(edge);
+                erased_counts[neighbor] -= 1;
+                if value {
+                    parities.flip(neighbor);
+                }
+                if erased_counts[neighbor] == 1 {
+                    resolvable.push(neighbor);
+                }
+            }
+        }
+        recovered
+    }
+}
+
+impl Decoder for Peeling {
+    fn decode(&mut self, llrs: &[f64]) -> Decoding {
+        self.graph.check_llrs(llrs);
+        let mut word = self.graph.hard_decisions(llrs);
+        let mut erased = self.graph.erasures(llrs);
+        let recovered = self.peel(&mut word, &mut erased);
+        let converged = erased.is_zero() && self.graph.syndrome(&word).is_zero();
+        Decoding {
+            word,
+            iterations: recovered,
+            converged,
+            undetermined: erased,
+        }
+    }
+}
+
+impl Graph {
+    /// Runs the peeling decoder on the given erased variables
+    /// and returns the variables which are not recovered.
+    ///
+    /// The returned variables are the largest stopping set contained in the erased variables.
+    /// The subgraph where decoding is stuck is the subgraph induced by them
+    /// and their neighbors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the erased vector is not the number of variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::bits::BitVec;
+    /// use bigs::graph::Graph;
+    ///
+    /// // Constraint 0 checks variables 0 and 1 and constraint 1 checks variables 1 and 2.
+    /// let graph = Graph::from_variable_neighbors(vec![vec![0], vec![0, 1], vec![1]]);
+    ///
+    /// let erased: BitVec = vec![true, true, false].into_iter().collect();
+    /// assert!(graph.peel_erasures(&erased).is_zero());
+    ///
+    /// // Each constraint has 2 erased neighbors.
+    /// let erased: BitVec = vec![true, true, true].into_iter().collect();
+    /// assert_eq!(graph.peel_erasures(&erased), erased);
+    /// ```
+    pub fn peel_erasures(&self, erased: &BitVec) -> BitVec {
+        assert_eq!(
+            erased.len(),
+            self.number_of_variables(),
+            "there must be one bit per variable"
+        );
+        let mut remaining = erased.clone();
+        Peeling::new(self).peel(&mut BitVec::zeros(erased.len()), &mut remaining);
+        remaining
+    }
+}
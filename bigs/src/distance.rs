@@ -0,0 +1,121 @@
+//! Minimum distance of codes.
+//!
+//! The minimum distance of a linear code is the smallest weight of a nonzero codeword.
+//! Computing it is hard in general, so this module provides an exact computation
+//! for codes of small dimension and an upper bound for larger codes.
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::code::Code;
+//! use bigs::graph::Graph;
+//! use rand::thread_rng;
+//!
+//! // The Hamming code of length 7 whose parity-check matrix columns are
+//! // all the nonzero vectors of length 3.
+//! let graph = Graph::from_variable_neighbors(vec![
+//!     vec![0],
+//!     vec![1],
+//!     vec![0, 1],
+//!     vec![2],
+//!     vec![0, 2],
+//!     vec![1, 2],
+//!     vec![0, 1, 2],
+//! ]);
+//! let code = Code::new(graph);
+//!
+//! assert_eq!(code.minimum_distance(), Some(3));
+//! assert_eq!(code.minimum_distance_upper_bound_with(10, &mut thread_rng()), Some(3));
+//! ```
+
+use crate::code::Code;
+use crate::matrix::{DenseMatrix, WORD_SIZE};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl Code {
+    /// Returns the minimum distance of the code
+    /// or None if the code has no nonzero codeword.
+    ///
+    /// This enumerates all codewords in Gray code order,
+    /// so each codeword is obtained from the previous one by adding a single row
+    /// of the generator matrix.
+    /// It takes a time proportional to 2^k times the length of the code
+    /// where k is the dimension, so it is only practical for dimensions up to about 30.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is at least 64.
+    pub fn minimum_distance(&self) -> Option<usize> {
+        let dimension = self.dimension();
+        assert!(
+            dimension < 64,
+            "can't enumerate the codewords of a code of dimension {}",
+            dimension
+        );
+        let generator_matrix = self.generator_matrix();
+        let mut codeword = vec![0; self.length().div_ceil(WORD_SIZE)];
+        let mut minimum_distance = None;
+        for step in 1..1u64 << dimension {
+            let row = step.trailing_zeros() as usize;
+            for (word, row_word) in codeword.iter_mut().zip(generator_matrix.row_words(row)) {
+                *word ^= row_word;
+            }
+            let weight = codeword.iter().map(|word| word.count_ones() as usize).sum();
+            minimum_distance =
+                Some(minimum_distance.map_or(weight, |distance: usize| distance.min(weight)));
+        }
+        minimum_distance
+    }
+
+    /// Returns an upper bound on the minimum distance of the code
+    /// or None if the code has no nonzero codeword.
+    ///
+    /// At each of the given number of trials (at least one),
+    /// the columns of the generator matrix are shuffled
+    /// and the matrix is put in systematic form for this order,
+    /// that is, with respect to a random information set.
+    /// The bound is the smallest weight among the rows of these matrices
+    /// and the sums of pairs of rows.
+    /// Low-weight codewords are likely to appear as such rows,
+    /// so the bound is often tight after enough trials.
+    pub fn minimum_distance_upper_bound_with<R: Rng>(
+        &self,
+        number_of_trials: usize,
+        rng: &mut R,
+    ) -> Option<usize> {
+        let generator_matrix = self.generator_matrix();
+        let (dimension, length) = (self.dimension(), self.length());
+        if dimension == 0 {
+            return None;
+        }
+        let mut columns: Vec<usize> = (0..length).collect();
+        let mut upper_bound = usize::MAX;
+        for _ in 0..number_of_trials.max(1) {
+            columns.shuffle(rng);
+            let mut shuffled = DenseMatrix::zeros(dimension, length);
+            for row in 0..dimension {
+                for (new_column, &column) in columns.iter().enumerate() {
+                    if generator_matrix.get(row, column) {
+                        shuffled.set(row, new_column, true);
+                    }
+                }
+            }
+            let systematic = shuffled.row_echelon_form();
+            let rows = systematic.matrix();
+            for first in 0..dimension {
+                upper_bound = upper_bound.min(rows.row_weight(first));
+                for second in first + 1..dimension {
+                    let weight = rows
+                        .row_words(first)
+                        .iter()
+                        .zip(rows.row_words(second))
+                        .map(|(first, second)| (first ^ second).count_ones() as usize)
+                        .sum();
+                    upper_bound = upper_bound.min(weight);
+                }
+            }
+        }
+        Some(upper_bound)
+    }
+}
@@ -1,5 +1,7 @@
+use crate::graph::Edge;
 use std::error::Error;
 use std::fmt;
+use std::io;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct InvalidParameters {
@@ -23,3 +25,121 @@ impl fmt::Display for InvalidParameters {
 }
 
 impl Error for InvalidParameters {}
+
+/// An inconsistency between the edges of a graph and its neighbor lists.
+///
+/// This is returned by [`Graph::validate`](crate::graph::Graph::validate).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum InvalidGraph {
+    /// An edge refers to a variable or a constraint outside of the graph.
+    EdgeOutOfRange(Edge),
+    /// An edge is not listed in the neighbors of its variable.
+    MissingVariableNeighbor(Edge),
+    /// An edge is not listed in the neighbors of its constraint.
+    MissingConstraintNeighbor(Edge),
+    /// A pair of neighbors do not correspond to an edge of the graph.
+    UnknownEdge(Edge),
+}
+
+impl fmt::Display for InvalidGraph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EdgeOutOfRange(edge) => write!(
+                f,
+                "edge ({}, {}) refers to a node outside of the graph",
+                edge.variable, edge.constraint
+            ),
+            Self::MissingVariableNeighbor(edge) => write!(
+                f,
+                "constraint {} is not a neighbor of variable {}",
+                edge.constraint, edge.variable
+            ),
+            Self::MissingConstraintNeighbor(edge) => write!(
+                f,
+                "variable {} is not a neighbor of constraint {}",
+                edge.variable, edge.constraint
+            ),
+            Self::UnknownEdge(edge) => write!(
+                f,
+                "variable {} and constraint {} are neighbors without an edge",
+                edge.variable, edge.constraint
+            ),
+        }
+    }
+}
+
+impl Error for InvalidGraph {}
+
+/// An error while reading a graph from a file or any other source.
+#[derive(Debug)]
+pub enum ReadError {
+    /// The source could not be read.
+    Io(io::Error),
+    /// The content of the source is not a valid graph.
+    Malformed {
+        /// The line where the problem was found, starting from 1.
+        line: usize,
+        /// A description of the problem.
+        reason: String,
+    },
+    /// The source is well formed, but does not describe a valid graph.
+    Invalid(String),
+    /// The binary source was written with an unknown version of the format.
+    UnsupportedVersion(u8),
+    /// The content of a binary source is not a valid graph.
+    Corrupted {
+        /// The position in bytes where the problem was found.
+        offset: u64,
+        /// A description of the problem.
+        reason: String,
+    },
+}
+
+impl ReadError {
+    pub(crate) fn malformed(line: usize, reason: impl Into<String>) -> Self {
+        Self::Malformed {
+            line,
+            reason: reason.into(),
+        }
+    }
+
+    pub(crate) fn corrupted(offset: u64, reason: impl Into<String>) -> Self {
+        Self::Corrupted {
+            offset,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "can't read graph: {}", error),
+            Self::Malformed { line, reason } => {
+                write!(f, "malformed graph at line {}: {}", line, reason)
+            }
+            Self::Invalid(reason) => write!(f, "invalid graph: {}", reason),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported binary format version {}", version)
+            }
+            Self::Corrupted { offset, reason } => {
+                write!(f, "corrupted graph at byte {}: {}", offset, reason)
+            }
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
@@ -0,0 +1,42 @@
+//! Reading and writing graphs in file formats used by other tools.
+//!
+//! All readers and writers are methods of [`Graph`](crate::graph::Graph).
+//! Readers take any [`BufRead`](std::io::BufRead) source and return a
+//! [`ReadError`](crate::error::ReadError) on invalid input.
+//! Writers take any [`Write`](std::io::Write) destination.
+//!
+//! The supported formats are:
+//! - [alist](crate::graph::Graph::write_alist), the format of MacKay's code database,
+//! - [edge lists](crate::graph::Graph::write_edge_list), the simplest text format,
+//! - [node-link JSON](crate::graph::Graph::write_node_link), the JSON format of NetworkX,
+//! - [DIMACS CNF](crate::graph::Graph::write_dimacs_cnf), the format of SAT solvers (write only),
+//! - [DOT](crate::graph::Graph::write_dot), the format of Graphviz (write only),
+//! - [GraphML](crate::graph::Graph::write_graphml), an XML format for general graph tools (write only),
+//! - [Matrix Market](crate::graph::Graph::write_matrix_market), a text format for sparse matrices (write only),
+//! - [NumPy](crate::graph::Graph::write_npy) dense arrays and
+//!   [SciPy](crate::graph::Graph::write_npz) sparse matrices (write only),
+//! - a [compact binary format](BinaryWriter) specific to bigs.
+
+mod alist;
+mod binary;
+mod dimacs;
+mod dot;
+mod edge_list;
+mod graphml;
+mod matrix_market;
+mod node_link;
+mod numpy;
+
+pub use binary::{BinaryReader, BinaryWriter};
+
+use crate::graph::{Edge, Graph};
+
+impl Graph {
+    // Returns the edges in increasing (variable, constraint) order
+    // to make the output of writers deterministic.
+    pub(crate) fn sorted_edges(&self) -> Vec<Edge> {
+        let mut edges: Vec<Edge> = self.edges().collect();
+        edges.sort_unstable_by_key(|edge| (edge.variable, edge.constraint));
+        edges
+    }
+}
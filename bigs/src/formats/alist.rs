@@ -0,0 +1,284 @@
+use crate::error::ReadError;
+use crate::graph::{Edge, Graph, Nodes};
+use std::io::{BufRead, Write};
+
+impl Graph {
+    /// Writes the graph in the alist format.
+    ///
+    /// The alist format of MacKay describes a parity-check matrix
+    /// where variables are columns and constraints are rows.
+    /// It contains, one item per line,
+    /// - the number of variables and the number of constraints,
+    /// - the maximum variable degree and the maximum constraint degree,
+    /// - the degree of each variable,
+    /// - the degree of each constraint,
+    /// - the neighbors of each variable, one variable per line,
+    /// - the neighbors of each constraint, one constraint per line.
+    ///
+    /// Labels are 1-based and neighbor lists are padded with 0
+    /// up to the maximum degree.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(0, 0));
+    /// graph.insert_edge(Edge::new(1, 0));
+    /// graph.insert_edge(Edge::new(1, 1));
+    ///
+    /// let mut alist = Vec::new();
+    /// graph.write_alist(&mut alist).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(alist).unwrap(),
+    ///     "2 2\n2 2\n1 2\n2 1\n1 0\n1 2\n1 2\n2 0\n"
+    /// );
+    /// ```
+    pub fn write_alist<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        let variables = sorted_neighbors(self.variables());
+        let constraints = sorted_neighbors(self.constraints());
+        let max_variable_degree = max_degree(&variables);
+        let max_constraint_degree = max_degree(&constraints);
+        writeln!(
+            writer,
+            "{} {}",
+            self.number_of_variables(),
+            self.number_of_constraints()
+        )?;
+        writeln!(writer, "{} {}", max_variable_degree, max_constraint_degree)?;
+        write_line(&mut writer, variables.iter().map(Vec::len))?;
+        write_line(&mut writer, constraints.iter().map(Vec::len))?;
+        for neighbors in variables.iter() {
+            write_padded_neighbors(&mut writer, neighbors, max_variable_degree)?;
+        }
+        for neighbors in constraints.iter() {
+            write_padded_neighbors(&mut writer, neighbors, max_constraint_degree)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a graph in the alist format.
+    ///
+    /// See [`write_alist`](Graph::write_alist) for a description of the format.
+    /// Padding zeros in neighbor lists are optional,
+    /// except that a node without neighbors is written as a line of zeros
+    /// when some nodes on the same side have neighbors.
+    /// An error is returned if the neighbors of the constraints
+    /// do not agree with the neighbors of the variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let alist = "2 2\n2 2\n1 2\n2 1\n1 0\n1 2\n1 2\n2 0\n";
+    /// let graph = Graph::read_alist(alist.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(graph.number_of_variables(), 2);
+    /// assert_eq!(graph.number_of_constraints(), 2);
+    /// assert!(graph.contains_edge(Edge::new(0, 0)));
+    /// assert!(graph.contains_edge(Edge::new(1, 0)));
+    /// assert!(graph.contains_edge(Edge::new(1, 1)));
+    ///
+    /// let truncated = "2 2\n2 2\n1 2\n2 1\n1 0\n";
+    /// assert!(Graph::read_alist(truncated.as_bytes()).is_err());
+    /// ```
+    pub fn read_alist<R: BufRead>(reader: R) -> Result<Self, ReadError> {
+        let mut lines = Lines::new(reader);
+        let (_, sizes) = lines.next_numbers(2, "number of variables and constraints")?;
+        let (number_of_variables, number_of_constraints) = (sizes[0], sizes[1]);
+        let (line, max_degrees) = lines.next_numbers(2, "maximum degrees")?;
+        let (_, variable_degrees) = lines.next_numbers(number_of_variables, "variable degrees")?;
+        let (_, constraint_degrees) =
+            lines.next_numbers(number_of_constraints, "constraint degrees")?;
+        check_max_degree(line, &variable_degrees, max_degrees[0])?;
+        check_max_degree(line, &constraint_degrees, max_degrees[1])?;
+
+        let mut graph = Graph::with_nodes(number_of_variables, number_of_constraints);
+        for (variable, &degree) in variable_degrees.iter().enumerate() {
+            let (line, neighbors) =
+                lines.next_neighbors(degree, max_degrees[0], number_of_constraints)?;
+            for constraint in neighbors {
+                if !graph.insert_edge(Edge::new(variable, constraint)) {
+                    return Err(ReadError::malformed(
+                        line,
+                        format!("constraint {} appears twice", constraint + 1),
+                    ));
+                }
+            }
+        }
+        for (constraint, &degree) in constraint_degrees.iter().enumerate() {
+            let (line, mut neighbors) =
+                lines.next_neighbors(degree, max_degrees[1], number_of_variables)?;
+            neighbors.sort_unstable();
+            if let Some(pair) = neighbors.windows(2).find(|pair| pair[0] == pair[1]) {
+                return Err(ReadError::malformed(
+                    line,
+                    format!("variable {} appears twice", pair[0] + 1),
+                ));
+            }
+            let expected_neighbors = &graph.constraint_neighbors[constraint];
+            if neighbors.len() != expected_neighbors.len()
+                || neighbors
+                    .iter()
+                    .any(|variable| !expected_neighbors.contains(variable))
+            {
+                return Err(ReadError::malformed(
+                    line,
+                    format!(
+                        "neighbors of constraint {} do not agree with the neighbors of the variables",
+                        constraint + 1
+                    ),
+                ));
+            }
+        }
+        Ok(graph)
+    }
+}
+
+fn sorted_neighbors(nodes: Nodes) -> Vec<Vec<usize>> {
+    nodes
+        .map(|node| {
+            let mut neighbors: Vec<usize> = node.neighbors().iter().cloned().collect();
+            neighbors.sort_unstable();
+            neighbors
+        })
+        .collect()
+}
+
+fn max_degree(neighbors: &[Vec<usize>]) -> usize {
+    neighbors.iter().map(Vec::len).max().unwrap_or(0)
+}
+
+fn write_line<W: Write>(
+    writer: &mut W,
+    numbers: impl Iterator<Item = usize>,
+) -> std::io::Result<()> {
+    let numbers: Vec<String> = numbers.map(|number| number.to_string()).collect();
+    writeln!(writer, "{}", numbers.join(" "))
+}
+
+fn write_padded_neighbors<W: Write>(
+    writer: &mut W,
+    neighbors: &[usize],
+    max_degree: usize,
+) -> std::io::Result<()> {
+    let padding = std::iter::repeat(0).take(max_degree - neighbors.len());
+    write_line(
+        writer,
+        neighbors.iter().map(|label| label + 1).chain(padding),
+    )
+}
+
+fn check_max_degree(line: usize, degrees: &[usize], max_degree: usize) -> Result<(), ReadError> {
+    match degrees.iter().find(|&&degree| degree > max_degree) {
+        Some(degree) => Err(ReadError::malformed(
+            line,
+            format!(
+                "degree {} is larger than the maximum degree {}",
+                degree, max_degree
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+// The non-empty lines of an alist source, parsed as numbers.
+struct Lines<R> {
+    reader: R,
+    line: usize,
+    buffer: String,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: 0,
+            buffer: String::new(),
+        }
+    }
+
+    fn next_line(&mut self, what: &str) -> Result<(usize, Vec<usize>), ReadError> {
+        loop {
+            self.buffer.clear();
+            self.line += 1;
+            if self.reader.read_line(&mut self.buffer)? == 0 {
+                return Err(ReadError::malformed(
+                    self.line,
+                    format!("unexpected end of file while reading {}", what),
+                ));
+            }
+            if !self.buffer.trim().is_empty() {
+                break;
+            }
+        }
+        let numbers = self
+            .buffer
+            .split_whitespace()
+            .map(|token| {
+                token.parse().map_err(|_| {
+                    ReadError::malformed(self.line, format!("{:?} is not a valid number", token))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((self.line, numbers))
+    }
+
+    fn next_numbers(&mut self, count: usize, what: &str) -> Result<(usize, Vec<usize>), ReadError> {
+        let (line, numbers) = if count == 0 {
+            (self.line, Vec::new())
+        } else {
+            self.next_line(what)?
+        };
+        if numbers.len() != count {
+            return Err(ReadError::malformed(
+                line,
+                format!(
+                    "expected {} numbers for {}, found {}",
+                    count,
+                    what,
+                    numbers.len()
+                ),
+            ));
+        }
+        Ok((line, numbers))
+    }
+
+    // Reads a 1-based neighbor list of the given degree, possibly padded with zeros,
+    // and returns the 0-based labels.
+    fn next_neighbors(
+        &mut self,
+        degree: usize,
+        max_degree: usize,
+        number_of_neighbors: usize,
+    ) -> Result<(usize, Vec<usize>), ReadError> {
+        if max_degree == 0 {
+            // Empty neighbor lists are empty lines which are skipped.
+            return Ok((self.line, Vec::new()));
+        }
+        let (line, numbers) = self.next_line("neighbors")?;
+        let (neighbors, padding) = numbers.split_at(degree.min(numbers.len()));
+        if neighbors.len() != degree || padding.iter().any(|&label| label != 0) {
+            return Err(ReadError::malformed(
+                line,
+                format!("expected {} neighbors", degree),
+            ));
+        }
+        neighbors
+            .iter()
+            .map(|&label| {
+                if label == 0 || label > number_of_neighbors {
+                    Err(ReadError::malformed(
+                        line,
+                        format!("neighbor {} is out of range", label),
+                    ))
+                } else {
+                    Ok(label - 1)
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(|neighbors| (line, neighbors))
+    }
+}
@@ -0,0 +1,296 @@
+use crate::error::ReadError;
+use crate::graph::{Edge, Graph};
+use std::io::{BufRead, Write};
+
+const MAGIC: &[u8; 4] = b"BIGS";
+const VERSION: u8 = 1;
+const IRREGULAR: u8 = 0;
+const REGULAR: u8 = 1;
+
+impl Graph {
+    /// Writes the graph in the compact binary format of bigs.
+    ///
+    /// This is the same as writing a single graph with a [`BinaryWriter`](BinaryWriter).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::Graph;
+    ///
+    /// let graph = Graph::complete_graph(3, 2);
+    ///
+    /// let mut bytes = Vec::new();
+    /// graph.write_binary(&mut bytes).unwrap();
+    /// assert_eq!(Graph::read_binary(bytes.as_slice()).unwrap(), graph);
+    /// ```
+    pub fn write_binary<W: Write>(&self, writer: W) -> std::io::Result<()> {
+        let mut writer = BinaryWriter::new(writer)?;
+        writer.write(self)?;
+        writer.finish().map(|_| ())
+    }
+
+    /// Reads the first graph written in the compact binary format of bigs.
+    ///
+    /// See [`BinaryReader`](BinaryReader) to read all graphs of a source.
+    pub fn read_binary<R: BufRead>(reader: R) -> Result<Self, ReadError> {
+        BinaryReader::new(reader)?
+            .next()
+            .unwrap_or_else(|| Err(ReadError::corrupted(5, "no graph in source")))
+    }
+}
+
+/// A writer for a sequence of graphs in the compact binary format of bigs.
+///
+/// The format starts with the 4 bytes `BIGS` followed by a version byte (currently 1).
+/// Then, each graph is written one after the other as
+/// - the number of variables and the number of constraints,
+/// - a byte which is 1 if all variables have the same degree and 0 otherwise,
+/// - the common variable degree or the degree of each variable,
+/// - the neighbors of each variable in increasing order.
+///
+/// All numbers except the flag byte are LEB128 variable-length integers.
+/// Each neighbor list is delta-encoded: the first neighbor is written as is
+/// and then the difference with the previous neighbor is written.
+/// Thus, a graph with small degrees takes about one byte per edge.
+/// The neighbors of constraints are not stored since they can be recovered
+/// from the neighbors of variables.
+///
+/// Graphs are written as soon as they are given to the writer,
+/// so it can be used to store many samples without keeping them in memory.
+///
+/// # Example
+///
+/// ```
+/// use bigs::formats::{BinaryReader, BinaryWriter};
+/// use bigs::Sampler;
+/// use rand::thread_rng;
+///
+/// let sampler = Sampler::builder()
+///     .number_of_variables(10)
+///     .number_of_constraints(6)
+///     .variable_degree(3)
+///     .constraint_degree(5)
+///     .build()
+///     .unwrap();
+///
+/// let mut writer = BinaryWriter::new(Vec::new()).unwrap();
+/// for _ in 0..5 {
+///     writer.write(&sampler.sample_with(&mut thread_rng())).unwrap();
+/// }
+/// let bytes = writer.finish().unwrap();
+///
+/// let reader = BinaryReader::new(bytes.as_slice()).unwrap();
+/// let graphs = reader.collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(graphs.len(), 5);
+/// ```
+#[derive(Debug)]
+pub struct BinaryWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> BinaryWriter<W> {
+    /// Creates a writer and writes the header of the format.
+    pub fn new(mut writer: W) -> std::io::Result<Self> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        Ok(Self { writer })
+    }
+
+    /// Writes a graph.
+    pub fn write(&mut self, graph: &Graph) -> std::io::Result<()> {
+        let mut bytes = Vec::with_capacity(2 * graph.number_of_edges() + 16);
+        write_varint(&mut bytes, graph.number_of_variables() as u64);
+        write_varint(&mut bytes, graph.number_of_constraints() as u64);
+        let degrees: Vec<usize> = graph
+            .variables()
+            .map(|variable| variable.degree())
+            .collect();
+        match degrees.first() {
+            Some(&degree) if degrees.iter().all(|&other| other == degree) => {
+                bytes.push(REGULAR);
+                write_varint(&mut bytes, degree as u64);
+            }
+            _ => {
+                bytes.push(IRREGULAR);
+                for &degree in degrees.iter() {
+                    write_varint(&mut bytes, degree as u64);
+                }
+            }
+        }
+        for variable in graph.variables() {
+            let mut neighbors: Vec<usize> = variable.neighbors().iter().cloned().collect();
+            neighbors.sort_unstable();
+            let mut previous = 0;
+            for neighbor in neighbors {
+                write_varint(&mut bytes, (neighbor - previous) as u64);
+                previous = neighbor;
+            }
+        }
+        self.writer.write_all(&bytes)
+    }
+
+    /// Flushes the underlying writer and returns it.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// A reader for a sequence of graphs in the compact binary format of bigs.
+///
+/// This is an iterator over the graphs of the source.
+/// See [`BinaryWriter`](BinaryWriter) for a description of the format.
+#[derive(Debug)]
+pub struct BinaryReader<R> {
+    reader: R,
+    offset: u64,
+    failed: bool,
+}
+
+impl<R: BufRead> BinaryReader<R> {
+    /// Creates a reader and checks the header of the format.
+    pub fn new(mut reader: R) -> Result<Self, ReadError> {
+        let mut header = [0; 5];
+        reader.read_exact(&mut header).map_err(|error| {
+            if error.kind() == std::io::ErrorKind::UnexpectedEof {
+                ReadError::corrupted(0, "missing header")
+            } else {
+                ReadError::Io(error)
+            }
+        })?;
+        if &header[..4] != MAGIC {
+            return Err(ReadError::corrupted(0, "not a bigs binary file"));
+        }
+        if header[4] != VERSION {
+            return Err(ReadError::UnsupportedVersion(header[4]));
+        }
+        Ok(Self {
+            reader,
+            offset: 5,
+            failed: false,
+        })
+    }
+
+    fn read_graph(&mut self) -> Result<Graph, ReadError> {
+        let offset = self.offset;
+        let number_of_variables = self.read_size("number of variables")?;
+        let number_of_constraints = self.read_size("number of constraints")?;
+        // The counts are untrusted, so degrees and edges are only stored once they are read.
+        let mut edges = Vec::new();
+        match self.read_byte("degree flag")? {
+            REGULAR => {
+                let degree = self.read_size("variable degree")?;
+                if degree > 0 {
+                    for variable in 0..number_of_variables {
+                        self.read_neighbors(variable, degree, number_of_constraints, &mut edges)?;
+                    }
+                }
+            }
+            IRREGULAR => {
+                let mut degrees = Vec::new();
+                for _ in 0..number_of_variables {
+                    degrees.push(self.read_size("variable degree")?);
+                }
+                for (variable, degree) in degrees.into_iter().enumerate() {
+                    self.read_neighbors(variable, degree, number_of_constraints, &mut edges)?;
+                }
+            }
+            flag => {
+                return Err(ReadError::corrupted(
+                    self.offset - 1,
+                    format!("invalid degree flag {}", flag),
+                ))
+            }
+        }
+        let mut graph = Graph::try_with_nodes(number_of_variables, number_of_constraints)
+            .ok_or_else(|| ReadError::corrupted(offset, "too many nodes"))?;
+        graph.extend(edges);
+        Ok(graph)
+    }
+
+    fn read_neighbors(
+        &mut self,
+        variable: usize,
+        degree: usize,
+        number_of_constraints: usize,
+        edges: &mut Vec<Edge>,
+    ) -> Result<(), ReadError> {
+        let mut constraint: usize = 0;
+        for position in 0..degree {
+            let offset = self.offset;
+            let delta = self.read_size("neighbor")?;
+            if position > 0 && delta == 0 {
+                return Err(ReadError::corrupted(offset, "repeated neighbor"));
+            }
+            constraint = match constraint.checked_add(delta) {
+                Some(constraint) if constraint < number_of_constraints => constraint,
+                _ => return Err(ReadError::corrupted(offset, "constraint is out of range")),
+            };
+            edges.push(Edge::new(variable, constraint));
+        }
+        Ok(())
+    }
+
+    fn read_byte(&mut self, what: &str) -> Result<u8, ReadError> {
+        let mut byte = [0];
+        match self.reader.read_exact(&mut byte) {
+            Ok(()) => {
+                self.offset += 1;
+                Ok(byte[0])
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => {
+                Err(ReadError::corrupted(
+                    self.offset,
+                    format!("unexpected end while reading {}", what),
+                ))
+            }
+            Err(error) => Err(ReadError::Io(error)),
+        }
+    }
+
+    fn read_size(&mut self, what: &str) -> Result<usize, ReadError> {
+        let offset = self.offset;
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_byte(what)?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return usize::try_from(value)
+                    .map_err(|_| ReadError::corrupted(offset, format!("{} is too large", what)));
+            }
+        }
+        Err(ReadError::corrupted(
+            offset,
+            format!("{} is too large", what),
+        ))
+    }
+}
+
+impl<R: BufRead> Iterator for BinaryReader<R> {
+    type Item = Result<Graph, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.reader.fill_buf() {
+            Ok([]) => return None,
+            Ok(_) => (),
+            Err(error) => {
+                self.failed = true;
+                return Some(Err(ReadError::Io(error)));
+            }
+        }
+        let graph = self.read_graph();
+        self.failed = graph.is_err();
+        Some(graph)
+    }
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
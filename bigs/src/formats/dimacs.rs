@@ -0,0 +1,94 @@
+use crate::graph::{Edge, Graph};
+use rand::Rng;
+use std::io::Write;
+
+impl Graph {
+    /// Writes the graph as a SAT formula in the DIMACS CNF format.
+    ///
+    /// Each constraint becomes a clause over its neighbor variables.
+    /// Variable v is written as the DIMACS variable v + 1
+    /// and the literal of an edge is negated if `is_negated` returns true for it.
+    /// The literals of each clause are written in increasing variable order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(0, 0));
+    /// graph.insert_edge(Edge::new(1, 0));
+    /// graph.insert_edge(Edge::new(1, 1));
+    /// graph.insert_edge(Edge::new(2, 1));
+    ///
+    /// let mut cnf = Vec::new();
+    /// // Negates the literals of odd variables.
+    /// graph
+    ///     .write_dimacs_cnf(&mut cnf, |edge| edge.variable % 2 == 1)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(cnf).unwrap(), "p cnf 3 2\n1 -2 0\n-2 3 0\n");
+    /// ```
+    pub fn write_dimacs_cnf<W, F>(&self, mut writer: W, mut is_negated: F) -> std::io::Result<()>
+    where
+        W: Write,
+        F: FnMut(Edge) -> bool,
+    {
+        writeln!(
+            writer,
+            "p cnf {} {}",
+            self.number_of_variables(),
+            self.number_of_constraints()
+        )?;
+        for constraint in self.constraints() {
+            let mut variables: Vec<usize> = constraint.neighbors().iter().cloned().collect();
+            variables.sort_unstable();
+            for variable in variables {
+                let sign = if is_negated(Edge::new(variable, constraint.label())) {
+                    "-"
+                } else {
+                    ""
+                };
+                write!(writer, "{}{} ", sign, variable + 1)?;
+            }
+            writeln!(writer, "0")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the graph as a SAT formula in the DIMACS CNF format
+    /// where each literal is negated with probability 1/2.
+    ///
+    /// See [`write_dimacs_cnf`](Graph::write_dimacs_cnf) for more details.
+    /// Sampling a graph and writing it with this method
+    /// generates a random regular k-SAT formula.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::Sampler;
+    /// use rand::thread_rng;
+    ///
+    /// // A random 3-SAT formula with 20 variables each appearing in 6 clauses.
+    /// let sampler = Sampler::builder()
+    ///     .number_of_variables(20)
+    ///     .number_of_constraints(40)
+    ///     .variable_degree(6)
+    ///     .constraint_degree(3)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut cnf = Vec::new();
+    /// sampler
+    ///     .sample_with(&mut thread_rng())
+    ///     .write_random_dimacs_cnf(&mut cnf, &mut thread_rng())
+    ///     .unwrap();
+    /// ```
+    pub fn write_random_dimacs_cnf<W: Write, R: Rng>(
+        &self,
+        writer: W,
+        rng: &mut R,
+    ) -> std::io::Result<()> {
+        self.write_dimacs_cnf(writer, |_| rng.gen())
+    }
+}
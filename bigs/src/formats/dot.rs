@@ -0,0 +1,40 @@
+use crate::graph::Graph;
+use std::io::Write;
+
+impl Graph {
+    /// Writes the graph in the Graphviz DOT format.
+    ///
+    /// Variable v is the node `v<v>` drawn as a circle
+    /// and constraint c is the node `c<c>` drawn as a box.
+    /// Edges are written in increasing (variable, constraint) order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(1, 0));
+    /// graph.insert_edge(Edge::new(0, 0));
+    ///
+    /// let mut dot = Vec::new();
+    /// graph.write_dot(&mut dot).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(dot).unwrap(),
+    ///     "graph {\n    v0 [shape=circle];\n    v1 [shape=circle];\n    c0 [shape=box];\n    v0 -- c0;\n    v1 -- c0;\n}\n"
+    /// );
+    /// ```
+    pub fn write_dot<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writeln!(writer, "graph {{")?;
+        for variable in 0..self.number_of_variables() {
+            writeln!(writer, "    v{} [shape=circle];", variable)?;
+        }
+        for constraint in 0..self.number_of_constraints() {
+            writeln!(writer, "    c{} [shape=box];", constraint)?;
+        }
+        for edge in self.sorted_edges() {
+            writeln!(writer, "    v{} -- c{};", edge.variable, edge.constraint)?;
+        }
+        writeln!(writer, "}}")
+    }
+}
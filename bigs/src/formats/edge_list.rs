@@ -0,0 +1,147 @@
+use crate::error::ReadError;
+use crate::graph::{Edge, Graph};
+use std::io::{BufRead, Write};
+
+impl Graph {
+    /// Writes the graph as a list of edges.
+    ///
+    /// The first line is the header `p <number of variables> <number of constraints>`.
+    /// Then, each edge is written on its own line as `<variable> <constraint>`
+    /// in increasing (variable, constraint) order.
+    /// Labels are 0-based.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(1, 0));
+    /// graph.insert_edge(Edge::new(0, 2));
+    ///
+    /// let mut edges = Vec::new();
+    /// graph.write_edge_list(&mut edges).unwrap();
+    /// assert_eq!(String::from_utf8(edges).unwrap(), "p 2 3\n0 2\n1 0\n");
+    /// ```
+    pub fn write_edge_list<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writeln!(
+            writer,
+            "p {} {}",
+            self.number_of_variables(),
+            self.number_of_constraints()
+        )?;
+        for edge in self.sorted_edges() {
+            writeln!(writer, "{} {}", edge.variable, edge.constraint)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a graph from a list of edges.
+    ///
+    /// Each line contains an edge as `<variable> <constraint>`
+    /// separated by any whitespace.
+    /// Empty lines and lines starting with `#` or `%` are ignored.
+    ///
+    /// The header `p <number of variables> <number of constraints>` is optional,
+    /// but must come before the first edge if present.
+    /// Without a header, the number of nodes is given by the largest labels.
+    /// With a header, labels must be smaller than the given number of nodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let edges = "# A small graph.\np 2 4\n0 2\n1\t0\n";
+    /// let graph = Graph::read_edge_list(edges.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(graph.number_of_variables(), 2);
+    /// assert_eq!(graph.number_of_constraints(), 4);
+    /// assert!(graph.contains_edge(Edge::new(0, 2)));
+    /// assert!(graph.contains_edge(Edge::new(1, 0)));
+    ///
+    /// let without_header = "0 2\n1 0\n";
+    /// let graph = Graph::read_edge_list(without_header.as_bytes()).unwrap();
+    /// assert_eq!(graph.number_of_constraints(), 3);
+    /// ```
+    pub fn read_edge_list<R: BufRead>(reader: R) -> Result<Self, ReadError> {
+        let mut graph = Graph::new();
+        let mut size = None;
+        let mut has_edges = false;
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('%') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('p') {
+                if has_edges || size.is_some() {
+                    return Err(ReadError::malformed(
+                        line_number,
+                        "the header must come before all edges",
+                    ));
+                }
+                let (variables, constraints) = parse_pair(line_number, header)?;
+                graph = Graph::try_with_nodes(variables, constraints).ok_or_else(|| {
+                    ReadError::malformed(
+                        line_number,
+                        format!(
+                            "can't allocate {} variables and {} constraints",
+                            variables, constraints
+                        ),
+                    )
+                })?;
+                size = Some((variables, constraints));
+                continue;
+            }
+            let (variable, constraint) = parse_pair(line_number, line)?;
+            if let Some((variables, constraints)) = size {
+                if variable >= variables || constraint >= constraints {
+                    return Err(ReadError::malformed(
+                        line_number,
+                        format!(
+                            "edge ({}, {}) is out of range for {} variables and {} constraints",
+                            variable, constraint, variables, constraints
+                        ),
+                    ));
+                }
+            }
+            // Without a header, the labels are untrusted and give the number of nodes.
+            variable
+                .checked_add(1)
+                .zip(constraint.checked_add(1))
+                .and_then(|(variables, constraints)| graph.try_grow(variables, constraints))
+                .ok_or_else(|| {
+                    ReadError::malformed(
+                        line_number,
+                        format!(
+                            "can't allocate the nodes of edge ({}, {})",
+                            variable, constraint
+                        ),
+                    )
+                })?;
+            graph.insert_edge(Edge::new(variable, constraint));
+            has_edges = true;
+        }
+        Ok(graph)
+    }
+}
+
+fn parse_pair(line_number: usize, line: &str) -> Result<(usize, usize), ReadError> {
+    let numbers = line
+        .split_whitespace()
+        .map(|token| {
+            token.parse().map_err(|_| {
+                ReadError::malformed(line_number, format!("{:?} is not a valid label", token))
+            })
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+    match numbers.as_slice() {
+        &[first, second] => Ok((first, second)),
+        _ => Err(ReadError::malformed(
+            line_number,
+            format!("expected 2 numbers, found {}", numbers.len()),
+        )),
+    }
+}
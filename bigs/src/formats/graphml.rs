@@ -0,0 +1,62 @@
+use crate::graph::Graph;
+use std::io::Write;
+
+impl Graph {
+    /// Writes the graph in the GraphML format.
+    ///
+    /// Variable v is the node `v<v>` and constraint c is the node `c<c>`.
+    /// Each node has a `side` attribute which is either `variable` or `constraint`.
+    /// Edges are written in increasing (variable, constraint) order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(0, 0));
+    ///
+    /// let mut graphml = Vec::new();
+    /// graph.write_graphml(&mut graphml).unwrap();
+    /// let graphml = String::from_utf8(graphml).unwrap();
+    ///
+    /// assert!(graphml.contains(r#"<node id="v0"><data key="side">variable</data></node>"#));
+    /// assert!(graphml.contains(r#"<node id="c0"><data key="side">constraint</data></node>"#));
+    /// assert!(graphml.contains(r#"<edge source="v0" target="c0"/>"#));
+    /// ```
+    pub fn write_graphml<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )?;
+        writeln!(
+            writer,
+            r#"  <key id="side" for="node" attr.name="side" attr.type="string"/>"#
+        )?;
+        writeln!(writer, r#"  <graph id="G" edgedefault="undirected">"#)?;
+        for variable in 0..self.number_of_variables() {
+            writeln!(
+                writer,
+                r#"    <node id="v{}"><data key="side">variable</data></node>"#,
+                variable
+            )?;
+        }
+        for constraint in 0..self.number_of_constraints() {
+            writeln!(
+                writer,
+                r#"    <node id="c{}"><data key="side">constraint</data></node>"#,
+                constraint
+            )?;
+        }
+        for edge in self.sorted_edges() {
+            writeln!(
+                writer,
+                r#"    <edge source="v{}" target="c{}"/>"#,
+                edge.variable, edge.constraint
+            )?;
+        }
+        writeln!(writer, "  </graph>")?;
+        writeln!(writer, "</graphml>")
+    }
+}
@@ -0,0 +1,42 @@
+use crate::graph::Graph;
+use std::io::Write;
+
+impl Graph {
+    /// Writes the parity-check matrix of the graph in the Matrix Market coordinate format.
+    ///
+    /// Rows are constraints and columns are variables.
+    /// Entries are 1-based and written in row-major order with an integer value of 1.
+    /// This can be loaded in Python with `scipy.io.mmread`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(0, 1));
+    /// graph.insert_edge(Edge::new(2, 0));
+    ///
+    /// let mut mtx = Vec::new();
+    /// graph.write_matrix_market(&mut mtx).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(mtx).unwrap(),
+    ///     "%%MatrixMarket matrix coordinate integer general\n2 3 2\n1 3 1\n2 1 1\n"
+    /// );
+    /// ```
+    pub fn write_matrix_market<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        let matrix = self.sparse_parity_check_matrix();
+        writeln!(writer, "%%MatrixMarket matrix coordinate integer general")?;
+        writeln!(
+            writer,
+            "{} {} {}",
+            matrix.number_of_rows(),
+            matrix.number_of_columns(),
+            matrix.number_of_nonzeros()
+        )?;
+        for (row, column) in matrix.coordinates() {
+            writeln!(writer, "{} {} 1", row + 1, column + 1)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::error::ReadError;
+use crate::graph::{Edge, Graph};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+impl Graph {
+    /// Writes the graph in the node-link JSON format of NetworkX.
+    ///
+    /// Variable v is the node with id v and constraint c is the node with id
+    /// c + number of variables.
+    /// Following the NetworkX convention for bipartite graphs,
+    /// each node has a `bipartite` attribute which is 0 for variables
+    /// and 1 for constraints.
+    /// Each link goes from a variable to a constraint.
+    ///
+    /// This can be loaded in Python with
+    /// `networkx.node_link_graph(json.load(file))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(0, 0));
+    ///
+    /// let mut json = Vec::new();
+    /// graph.write_node_link(&mut json).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(json).unwrap(),
+    ///     concat!(
+    ///         r#"{"directed":false,"multigraph":false,"graph":{},"#,
+    ///         r#""nodes":[{"id":0,"bipartite":0},{"id":1,"bipartite":1}],"#,
+    ///         r#""links":[{"source":0,"target":1}]}"#
+    ///     )
+    /// );
+    /// ```
+    pub fn write_node_link<W: Write>(&self, writer: W) -> std::io::Result<()> {
+        let number_of_variables = self.number_of_variables();
+        let variables = (0..number_of_variables).map(|variable| Node {
+            id: Value::from(variable),
+            bipartite: VARIABLE,
+        });
+        let constraints = (0..self.number_of_constraints()).map(|constraint| Node {
+            id: Value::from(constraint + number_of_variables),
+            bipartite: CONSTRAINT,
+        });
+        let links = self
+            .sorted_edges()
+            .into_iter()
+            .map(|edge| Link {
+                source: Value::from(edge.variable),
+                target: Value::from(edge.constraint + number_of_variables),
+            })
+            .collect();
+        let node_link = NodeLink {
+            directed: false,
+            multigraph: false,
+            graph: Map::new(),
+            nodes: variables.chain(constraints).collect(),
+            links,
+        };
+        serde_json::to_writer(writer, &node_link).map_err(std::io::Error::from)
+    }
+
+    /// Reads a graph in the node-link JSON format of NetworkX.
+    ///
+    /// Each node must have a `bipartite` attribute which is 0 for variables
+    /// and 1 for constraints.
+    /// Node ids can be any JSON value.
+    /// Variables and constraints are labeled in their order of appearance
+    /// in the list of nodes.
+    /// Links can go in both directions, but must join a variable and a constraint.
+    /// The list of links can be named either `links` or `edges`.
+    /// All other attributes are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let json = r#"{
+    ///     "nodes": [
+    ///         {"id": "x", "bipartite": 0},
+    ///         {"id": "a", "bipartite": 1},
+    ///         {"id": "y", "bipartite": 0}
+    ///     ],
+    ///     "links": [{"source": "a", "target": "y"}]
+    /// }"#;
+    /// let graph = Graph::read_node_link(json.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(graph.number_of_variables(), 2);
+    /// assert_eq!(graph.number_of_constraints(), 1);
+    /// assert!(graph.contains_edge(Edge::new(1, 0)));
+    /// ```
+    pub fn read_node_link<R: BufRead>(reader: R) -> Result<Self, ReadError> {
+        let node_link: NodeLink = serde_json::from_reader(reader).map_err(|error| {
+            if error.is_io() {
+                ReadError::Io(error.into())
+            } else {
+                ReadError::malformed(error.line(), error.to_string())
+            }
+        })?;
+        let mut labels = HashMap::with_capacity(node_link.nodes.len());
+        let mut number_of_variables = 0;
+        let mut number_of_constraints = 0;
+        for node in node_link.nodes.iter() {
+            let label = match node.bipartite {
+                VARIABLE => &mut number_of_variables,
+                CONSTRAINT => &mut number_of_constraints,
+                side => {
+                    return Err(ReadError::Invalid(format!(
+                        "node {} has bipartite attribute {}, expected 0 or 1",
+                        node.id, side
+                    )))
+                }
+            };
+            let key = node.id.to_string();
+            if labels.insert(key, (node.bipartite, *label)).is_some() {
+                return Err(ReadError::Invalid(format!(
+                    "node {} appears twice",
+                    node.id
+                )));
+            }
+            *label += 1;
+        }
+        let mut graph = Graph::with_nodes(number_of_variables, number_of_constraints);
+        for link in node_link.links.iter() {
+            let endpoint = |id: &Value| {
+                labels
+                    .get(&id.to_string())
+                    .cloned()
+                    .ok_or_else(|| ReadError::Invalid(format!("unknown node {}", id)))
+            };
+            let edge = match (endpoint(&link.source)?, endpoint(&link.target)?) {
+                ((VARIABLE, variable), (CONSTRAINT, constraint))
+                | ((CONSTRAINT, constraint), (VARIABLE, variable)) => {
+                    Edge::new(variable, constraint)
+                }
+                _ => {
+                    return Err(ReadError::Invalid(format!(
+                        "link between {} and {} is not between a variable and a constraint",
+                        link.source, link.target
+                    )))
+                }
+            };
+            graph.insert_edge(edge);
+        }
+        Ok(graph)
+    }
+}
+
+const VARIABLE: u8 = 0;
+const CONSTRAINT: u8 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct NodeLink {
+    #[serde(default)]
+    directed: bool,
+    #[serde(default)]
+    multigraph: bool,
+    #[serde(default)]
+    graph: Map<String, Value>,
+    nodes: Vec<Node>,
+    #[serde(alias = "edges")]
+    links: Vec<Link>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Node {
+    id: Value,
+    bipartite: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Link {
+    source: Value,
+    target: Value,
+}
@@ -0,0 +1,199 @@
+use crate::graph::Graph;
+use std::io::Write;
+
+impl Graph {
+    /// Writes the parity-check matrix of the graph as a dense NumPy `.npy` array.
+    ///
+    /// The array has shape (number of constraints, number of variables)
+    /// and `uint8` entries.
+    /// This can be loaded in Python with `numpy.load`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(0, 1));
+    /// graph.insert_edge(Edge::new(2, 0));
+    ///
+    /// let mut npy = Vec::new();
+    /// graph.write_npy(&mut npy).unwrap();
+    /// assert!(npy.starts_with(b"\x93NUMPY"));
+    /// assert_eq!(&npy[npy.len() - 6..], &[0, 0, 1, 1, 0, 0]);
+    /// ```
+    pub fn write_npy<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        let matrix = self.parity_check_matrix();
+        let shape = [matrix.number_of_rows(), matrix.number_of_columns()];
+        write_npy_header(&mut writer, "|u1", &shape)?;
+        for row in 0..matrix.number_of_rows() {
+            let entries: Vec<u8> = (0..matrix.number_of_columns())
+                .map(|column| matrix.get(row, column) as u8)
+                .collect();
+            writer.write_all(&entries)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the parity-check matrix of the graph as a sparse CSR matrix
+    /// in the `.npz` format of SciPy.
+    ///
+    /// The rows are constraints and the columns are variables.
+    /// This can be loaded in Python with `scipy.sparse.load_npz`.
+    /// The archive is not compressed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::Graph;
+    ///
+    /// let mut npz = Vec::new();
+    /// Graph::complete_graph(3, 2).write_npz(&mut npz).unwrap();
+    /// assert!(npz.starts_with(b"PK\x03\x04"));
+    /// ```
+    pub fn write_npz<W: Write>(&self, writer: W) -> std::io::Result<()> {
+        let matrix = self.sparse_parity_check_matrix();
+        let mut archive = ZipWriter::new(writer);
+
+        let mut indices = Vec::new();
+        write_npy_header(&mut indices, "<i8", &[matrix.number_of_nonzeros()])?;
+        for &column in matrix.column_indices() {
+            indices.write_all(&(column as i64).to_le_bytes())?;
+        }
+        archive.write_file("indices.npy", &indices)?;
+
+        let mut indptr = Vec::new();
+        write_npy_header(&mut indptr, "<i8", &[matrix.row_offsets().len()])?;
+        for &offset in matrix.row_offsets() {
+            indptr.write_all(&(offset as i64).to_le_bytes())?;
+        }
+        archive.write_file("indptr.npy", &indptr)?;
+
+        let mut format = Vec::new();
+        write_npy_header(&mut format, "|S3", &[])?;
+        format.write_all(b"csr")?;
+        archive.write_file("format.npy", &format)?;
+
+        let mut shape = Vec::new();
+        write_npy_header(&mut shape, "<i8", &[2])?;
+        shape.write_all(&(matrix.number_of_rows() as i64).to_le_bytes())?;
+        shape.write_all(&(matrix.number_of_columns() as i64).to_le_bytes())?;
+        archive.write_file("shape.npy", &shape)?;
+
+        let mut data = Vec::new();
+        write_npy_header(&mut data, "|u1", &[matrix.number_of_nonzeros()])?;
+        data.write_all(&vec![1; matrix.number_of_nonzeros()])?;
+        archive.write_file("data.npy", &data)?;
+
+        archive.finish()
+    }
+}
+
+// Writes the header of a version 1.0 npy file for a C-ordered array.
+fn write_npy_header<W: Write>(writer: &mut W, descr: &str, shape: &[usize]) -> std::io::Result<()> {
+    let shape = match shape {
+        [length] => format!("({},)", length),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|length| length.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr, shape
+    );
+    // The magic string, the version and the header length take 10 bytes
+    // and the total must be a multiple of 64 including the final newline.
+    let padding = 63 - (10 + header.len()) % 64;
+    header.extend(std::iter::repeat(' ').take(padding));
+    header.push('\n');
+    writer.write_all(b"\x93NUMPY\x01\x00")?;
+    writer.write_all(&(header.len() as u16).to_le_bytes())?;
+    writer.write_all(header.as_bytes())
+}
+
+// A minimal writer for zip archives whose files are stored without compression.
+struct ZipWriter<W> {
+    writer: W,
+    offset: u32,
+    central_directory: Vec<u8>,
+    number_of_files: u16,
+}
+
+impl<W: Write> ZipWriter<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            offset: 0,
+            central_directory: Vec::new(),
+            number_of_files: 0,
+        }
+    }
+
+    fn write_file(&mut self, name: &str, content: &[u8]) -> std::io::Result<()> {
+        let crc = crc32(content);
+        let size = content.len() as u32;
+        // Fields shared by the local header and the central directory:
+        // version needed, flags, compression, time, date, crc, sizes, name length, extra length.
+        let mut common = Vec::new();
+        common.extend_from_slice(&20u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+        common.extend_from_slice(&0x21u16.to_le_bytes());
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+
+        let mut local_header = b"PK\x03\x04".to_vec();
+        local_header.extend_from_slice(&common);
+        local_header.extend_from_slice(name.as_bytes());
+        self.writer.write_all(&local_header)?;
+        self.writer.write_all(content)?;
+
+        self.central_directory.extend_from_slice(b"PK\x01\x02");
+        self.central_directory
+            .extend_from_slice(&20u16.to_le_bytes());
+        self.central_directory.extend_from_slice(&common);
+        // Comment length, disk number, internal and external attributes.
+        self.central_directory.extend_from_slice(&[0; 10]);
+        self.central_directory
+            .extend_from_slice(&self.offset.to_le_bytes());
+        self.central_directory.extend_from_slice(name.as_bytes());
+
+        self.offset += local_header.len() as u32 + size;
+        self.number_of_files += 1;
+        Ok(())
+    }
+
+    fn finish(mut self) -> std::io::Result<()> {
+        self.writer.write_all(&self.central_directory)?;
+        self.writer.write_all(b"PK\x05\x06")?;
+        self.writer.write_all(&[0; 4])?;
+        self.writer.write_all(&self.number_of_files.to_le_bytes())?;
+        self.writer.write_all(&self.number_of_files.to_le_bytes())?;
+        self.writer
+            .write_all(&(self.central_directory.len() as u32).to_le_bytes())?;
+        self.writer.write_all(&self.offset.to_le_bytes())?;
+        self.writer.write_all(&0u16.to_le_bytes())?;
+        self.writer.flush()
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
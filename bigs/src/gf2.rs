@@ -0,0 +1,252 @@
+//! Linear algebra over GF(2).
+//!
+//! The code defined by a graph is the null space of its parity-check matrix.
+//! Its true dimension is the number of variables minus the rank of the matrix,
+//! which is larger than `n - m` whenever some constraints are linearly dependent.
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::graph::Graph;
+//!
+//! // Two copies of the same constraint on 3 variables.
+//! let graph = Graph::from_variable_neighbors(vec![vec![0, 1], vec![0, 1], vec![0, 1]]);
+//!
+//! assert_eq!(graph.rank(), 1);
+//! assert_eq!(graph.dimension(), 2);
+//! assert_eq!(graph.redundant_constraints(), vec![1]);
+//! ```
+
+use crate::graph::Graph;
+use crate::matrix::{DenseMatrix, WORD_SIZE};
+
+/// The reduced row echelon form of a binary matrix.
+///
+/// Each row has a pivot, the column of its first 1,
+/// pivots are in increasing order and each pivot column
+/// has a single 1, in the row of the pivot.
+/// Up to a permutation of the columns, this is the systematic form `[I | A]`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct RowEchelonForm {
+    matrix: DenseMatrix,
+    pivots: Vec<usize>,
+}
+
+impl RowEchelonForm {
+    /// Returns the reduced matrix.
+    ///
+    /// It has one row per pivot and the same columns as the original matrix.
+    pub fn matrix(&self) -> &DenseMatrix {
+        &self.matrix
+    }
+
+    /// Returns the pivot column of each row in increasing order.
+    pub fn pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// Returns the rank of the original matrix.
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// Returns the columns without a pivot in increasing order.
+    pub fn free_columns(&self) -> Vec<usize> {
+        let mut pivots = self.pivots.iter().peekable();
+        (0..self.matrix.number_of_columns())
+            .filter(|column| {
+                if pivots.peek() == Some(&column) {
+                    pivots.next();
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a basis of the null space of the original matrix.
+    ///
+    /// See [`DenseMatrix::null_space`](DenseMatrix::null_space) for the details.
+    /// Use this to avoid computing the row echelon form twice
+    /// when the free columns are also needed.
+    pub fn null_space(&self) -> DenseMatrix {
+        let free_columns = self.free_columns();
+        let mut basis = DenseMatrix::zeros(free_columns.len(), self.matrix.number_of_columns());
+        for (row, &free_column) in free_columns.iter().enumerate() {
+            basis.set(row, free_column, true);
+            for (pivot_row, &pivot) in self.pivots.iter().enumerate() {
+                if self.matrix.get(pivot_row, free_column) {
+                    basis.set(row, pivot, true);
+                }
+            }
+        }
+        basis
+    }
+}
+
+impl DenseMatrix {
+    /// Returns the reduced row echelon form of the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::matrix::DenseMatrix;
+    ///
+    /// let mut matrix = DenseMatrix::zeros(2, 3);
+    /// matrix.set(0, 1, true);
+    /// matrix.set(0, 2, true);
+    /// matrix.set(1, 0, true);
+    /// matrix.set(1, 2, true);
+    ///
+    /// let echelon = matrix.row_echelon_form();
+    /// assert_eq!(echelon.pivots(), &[0, 1]);
+    /// assert_eq!(echelon.free_columns(), vec![2]);
+    /// assert!(echelon.matrix().get(0, 0));
+    /// assert!(!echelon.matrix().get(0, 1));
+    /// ```
+    pub fn row_echelon_form(&self) -> RowEchelonForm {
+        let mut matrix = self.clone();
+        let mut pivots = Vec::new();
+        for column in 0..matrix.number_of_columns() {
+            let rank = pivots.len();
+            let pivot_row =
+                match (rank..matrix.number_of_rows()).find(|&row| matrix.get(row, column)) {
+                    Some(row) => row,
+                    None => continue,
+                };
+            matrix.swap_rows(rank, pivot_row);
+            for row in 0..matrix.number_of_rows() {
+                if row != rank && matrix.get(row, column) {
+                    matrix.add_row(rank, row);
+                }
+            }
+            pivots.push(column);
+            if pivots.len() == matrix.number_of_rows() {
+                break;
+            }
+        }
+        matrix.truncate_rows(pivots.len());
+        RowEchelonForm { matrix, pivots }
+    }
+
+    /// Returns the rank of the matrix over GF(2).
+    pub fn rank(&self) -> usize {
+        self.row_echelon_form().rank()
+    }
+
+    /// Returns a basis of the null space of the matrix.
+    ///
+    /// That is, a matrix whose rows are linearly independent vectors x
+    /// such that the product of the matrix with x is zero,
+    /// and span all such vectors.
+    /// There is one row per column without a pivot in the row echelon form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::matrix::DenseMatrix;
+    ///
+    /// let mut matrix = DenseMatrix::zeros(1, 3);
+    /// matrix.set(0, 0, true);
+    /// matrix.set(0, 1, true);
+    ///
+    /// let basis = matrix.null_space();
+    /// assert_eq!(basis.number_of_rows(), 2);
+    /// // The vectors 110 and 001.
+    /// assert!(basis.get(0, 0) && basis.get(0, 1) && !basis.get(0, 2));
+    /// assert!(!basis.get(1, 0) && !basis.get(1, 1) && basis.get(1, 2));
+    /// ```
+    pub fn null_space(&self) -> DenseMatrix {
+        self.row_echelon_form().null_space()
+    }
+
+    /// Returns the rows which are linear combinations of the previous rows
+    /// in increasing order.
+    ///
+    /// Removing these rows keeps the same row space and leaves
+    /// a number of rows equal to the rank.
+    pub fn dependent_rows(&self) -> Vec<usize> {
+        let mut basis: Vec<(usize, Vec<u64>)> = Vec::new();
+        let mut dependent_rows = Vec::new();
+        for row in 0..self.number_of_rows() {
+            let mut words = self.row_words(row).to_vec();
+            for (pivot, vector) in basis.iter() {
+                if has_bit(&words, *pivot) {
+                    add_words(&mut words, vector);
+                }
+            }
+            match first_bit(&words) {
+                Some(pivot) => {
+                    for (_, vector) in basis.iter_mut() {
+                        if has_bit(vector, pivot) {
+                            add_words(vector, &words);
+                        }
+                    }
+                    basis.push((pivot, words));
+                }
+                None => dependent_rows.push(row),
+            }
+        }
+        dependent_rows
+    }
+}
+
+fn has_bit(words: &[u64], column: usize) -> bool {
+    words[column / WORD_SIZE] >> (column % WORD_SIZE) & 1 == 1
+}
+
+fn first_bit(words: &[u64]) -> Option<usize> {
+    words
+        .iter()
+        .position(|&word| word != 0)
+        .map(|index| index * WORD_SIZE + words[index].trailing_zeros() as usize)
+}
+
+fn add_words(target: &mut [u64], source: &[u64]) {
+    for (target, source) in target.iter_mut().zip(source) {
+        *target ^= source;
+    }
+}
+
+impl Graph {
+    /// Returns the rank over GF(2) of the parity-check matrix of the graph.
+    pub fn rank(&self) -> usize {
+        self.parity_check_matrix().rank()
+    }
+
+    /// Returns the dimension of the code defined by the graph.
+    ///
+    /// That is, the number of variables minus the rank of the parity-check matrix.
+    pub fn dimension(&self) -> usize {
+        self.number_of_variables() - self.rank()
+    }
+
+    /// Returns the rate of the code defined by the graph.
+    ///
+    /// That is, the dimension divided by the number of variables,
+    /// or 0 if there are no variables.
+    pub fn rate(&self) -> f64 {
+        if self.number_of_variables() == 0 {
+            0.0
+        } else {
+            self.dimension() as f64 / self.number_of_variables() as f64
+        }
+    }
+
+    /// Returns the constraints whose parity check is the sum of the parity checks
+    /// of constraints with smaller labels, in increasing order.
+    ///
+    /// Every codeword satisfies these constraints as soon as it satisfies the others.
+    pub fn redundant_constraints(&self) -> Vec<usize> {
+        self.parity_check_matrix().dependent_rows()
+    }
+
+    /// Returns a basis of the codewords of the code defined by the graph.
+    ///
+    /// Each row of the returned matrix is a codeword, that is,
+    /// an assignment of the variables satisfying all parity checks.
+    pub fn null_space(&self) -> DenseMatrix {
+        self.parity_check_matrix().null_space()
+    }
+}
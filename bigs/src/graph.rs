@@ -4,8 +4,11 @@
 //! However, if you want to manually build graphs,
 //! you will need to use [`Edge`](Edge).
 
+use crate::error::InvalidGraph;
 use crate::Sampler;
 use indexmap::IndexSet;
+use rand::seq::SliceRandom;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 /// A (variable, constraint) pair.
@@ -79,21 +82,140 @@ impl Edge {
 /// assert_eq!(graph.number_of_variables(), 1);
 /// assert_eq!(graph.number_of_constraints(), 43);
 /// ```
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+///
+/// # Serialization
+///
+/// Graphs implement serde's `Serialize` and `Deserialize` traits
+/// with a stable and versioned representation.
+/// Deserialization fails if the input does not describe a valid graph.
+/// See the [`serialization`](crate::serialization) module for the details.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct Graph {
-    variable_neighbors: Vec<IndexSet<usize>>,
-    constraint_neighbors: Vec<IndexSet<usize>>,
-    edges: IndexSet<Edge>,
+    pub(crate) variable_neighbors: Vec<IndexSet<usize>>,
+    pub(crate) constraint_neighbors: Vec<IndexSet<usize>>,
+    pub(crate) edges: IndexSet<Edge>,
 }
 
 impl Graph {
     /// Creates a new empty graph.
     pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a graph with the given number of variables and constraints, but without edges.
+    ///
+    /// This is the only way to have variables or constraints without neighbors
+    /// whose labels are larger than all other labels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::with_nodes(3, 2);
+    /// graph.insert_edge(Edge::new(0, 0));
+    ///
+    /// assert_eq!(graph.number_of_variables(), 3);
+    /// assert_eq!(graph.number_of_constraints(), 2);
+    /// assert_eq!(graph.number_of_edges(), 1);
+    /// ```
+    pub fn with_nodes(number_of_variables: usize, number_of_constraints: usize) -> Self {
         Self {
-            variable_neighbors: Vec::new(),
-            constraint_neighbors: Vec::new(),
-            edges: IndexSet::new(),
+            variable_neighbors: vec![IndexSet::new(); number_of_variables],
+            constraint_neighbors: vec![IndexSet::new(); number_of_constraints],
+            ..Self::default()
+        }
+    }
+
+    /// Same as [`with_nodes`](Graph::with_nodes), but returns None
+    /// instead of panicking if the nodes can't be allocated.
+    ///
+    /// Readers use it since their numbers of nodes come from untrusted input.
+    pub(crate) fn try_with_nodes(
+        number_of_variables: usize,
+        number_of_constraints: usize,
+    ) -> Option<Self> {
+        let mut graph = Self::default();
+        graph.try_grow(number_of_variables, number_of_constraints)?;
+        Some(graph)
+    }
+
+    /// Adds variables and constraints without neighbors until the graph has at least
+    /// the given numbers of nodes, or returns None if they can't be allocated.
+    pub(crate) fn try_grow(
+        &mut self,
+        number_of_variables: usize,
+        number_of_constraints: usize,
+    ) -> Option<()> {
+        Self::try_grow_nodes(&mut self.variable_neighbors, number_of_variables)?;
+        Self::try_grow_nodes(&mut self.constraint_neighbors, number_of_constraints)
+    }
+
+    fn try_grow_nodes(neighbors: &mut Vec<IndexSet<usize>>, number_of_nodes: usize) -> Option<()> {
+        if let Some(additional) = number_of_nodes.checked_sub(neighbors.len()) {
+            neighbors.try_reserve_exact(additional).ok()?;
+            neighbors.resize(number_of_nodes, IndexSet::new());
         }
+        Some(())
+    }
+
+    /// Creates a graph from the given edges.
+    ///
+    /// Repeated edges are inserted only once and
+    /// the number of nodes is given by the largest labels.
+    /// This is the same as collecting the edges into a graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let graph = Graph::from_edges(vec![Edge::new(0, 1), Edge::new(2, 0)]);
+    ///
+    /// assert_eq!(graph.number_of_variables(), 3);
+    /// assert_eq!(graph.number_of_constraints(), 2);
+    /// assert_eq!(graph.number_of_edges(), 2);
+    /// ```
+    pub fn from_edges<I: IntoIterator<Item = Edge>>(edges: I) -> Self {
+        edges.into_iter().collect()
+    }
+
+    /// Creates a graph where the neighbors of variable v are the constraints
+    /// in `neighbors[v]`.
+    ///
+    /// The number of variables is the length of `neighbors`
+    /// and the number of constraints is given by the largest label.
+    /// Use [`with_nodes`](Graph::with_nodes) together with
+    /// [`extend`](Extend::extend) if trailing constraints have no neighbors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let graph = Graph::from_variable_neighbors(vec![vec![0, 1], vec![], vec![1]]);
+    ///
+    /// assert_eq!(graph.number_of_variables(), 3);
+    /// assert_eq!(graph.number_of_constraints(), 2);
+    /// assert!(graph.contains_edge(Edge::new(0, 0)));
+    /// assert!(graph.contains_edge(Edge::new(0, 1)));
+    /// assert!(graph.contains_edge(Edge::new(2, 1)));
+    /// ```
+    pub fn from_variable_neighbors(neighbors: Vec<Vec<usize>>) -> Self {
+        let number_of_constraints = neighbors
+            .iter()
+            .flatten()
+            .max()
+            .map_or(0, |&constraint| constraint + 1);
+        let mut graph = Self::with_nodes(neighbors.len(), number_of_constraints);
+        for (variable, constraints) in neighbors.into_iter().enumerate() {
+            graph.extend(
+                constraints
+                    .into_iter()
+                    .map(|constraint| Edge::new(variable, constraint)),
+            );
+        }
+        graph
     }
 
     /// Creates a complete graph
@@ -225,6 +347,239 @@ impl Graph {
         }
     }
 
+    /// Removes the given variable and all its edges from the graph
+    /// and returns the relabeling of the nodes,
+    /// or returns None if the variable is not in the graph.
+    ///
+    /// Unlike [`remove_edge`](Graph::remove_edge), this updates the number of variables:
+    /// the variables after the removed one are relabeled to keep the labels contiguous.
+    /// Since this rebuilds the graph,
+    /// use [`compact`](Graph::compact) to remove many nodes at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::Graph;
+    ///
+    /// let mut graph = Graph::complete_graph(3, 2);
+    /// let relabeling = graph.remove_variable(1).unwrap();
+    /// assert_eq!(relabeling.variables, vec![Some(0), None, Some(1)]);
+    /// assert_eq!(relabeling.constraints, vec![Some(0), Some(1)]);
+    ///
+    /// assert_eq!(graph, Graph::complete_graph(2, 2));
+    /// assert!(graph.remove_variable(2).is_none());
+    /// ```
+    pub fn remove_variable(&mut self, variable: usize) -> Option<Relabeling> {
+        if variable >= self.number_of_variables() {
+            return None;
+        }
+        Some(self.compact(&[variable], &[]))
+    }
+
+    /// Removes the given constraint and all its edges from the graph
+    /// and returns the relabeling of the nodes,
+    /// or returns None if the constraint is not in the graph.
+    ///
+    /// See [`remove_variable`](Graph::remove_variable) for more details.
+    pub fn remove_constraint(&mut self, constraint: usize) -> Option<Relabeling> {
+        if constraint >= self.number_of_constraints() {
+            return None;
+        }
+        Some(self.compact(&[], &[constraint]))
+    }
+
+    /// Removes the given variables and constraints together with their edges
+    /// and relabels the remaining nodes from 0 while keeping their relative order.
+    ///
+    /// Returns the map from the old labels to the new labels.
+    /// Nodes without neighbors that are not given are kept
+    /// and labels given more than once are removed once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a label is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::complete_graph(3, 2);
+    /// let relabeling = graph.compact(&[1], &[0]);
+    /// assert_eq!(relabeling.variables, vec![Some(0), None, Some(1)]);
+    /// assert_eq!(relabeling.constraints, vec![None, Some(0)]);
+    ///
+    /// assert_eq!(graph.number_of_variables(), 2);
+    /// assert_eq!(graph.number_of_constraints(), 1);
+    /// assert!(graph.contains_edge(Edge::new(0, 0)));
+    /// assert!(graph.contains_edge(Edge::new(1, 0)));
+    /// ```
+    pub fn compact(&mut self, variables: &[usize], constraints: &[usize]) -> Relabeling {
+        let relabeling = Relabeling {
+            variables: Self::compact_labels(variables, self.number_of_variables(), "variable"),
+            constraints: Self::compact_labels(
+                constraints,
+                self.number_of_constraints(),
+                "constraint",
+            ),
+        };
+        let number_of_nodes = |labels: &[Option<usize>]| labels.iter().flatten().count();
+        let mut graph = Self::with_nodes(
+            number_of_nodes(&relabeling.variables),
+            number_of_nodes(&relabeling.constraints),
+        );
+        graph.extend(self.edges().filter_map(|edge| relabeling.apply(edge)));
+        *self = graph;
+        relabeling
+    }
+
+    fn compact_labels(removed: &[usize], number_of_nodes: usize, kind: &str) -> Vec<Option<usize>> {
+        let mut is_removed = vec![false; number_of_nodes];
+        for &label in removed {
+            assert!(
+                label < number_of_nodes,
+                "{} {} is out of range",
+                kind,
+                label
+            );
+            is_removed[label] = true;
+        }
+        let mut next_label = 0;
+        is_removed
+            .into_iter()
+            .map(|is_removed| {
+                if is_removed {
+                    None
+                } else {
+                    next_label += 1;
+                    Some(next_label - 1)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a copy of the graph where variable v is relabeled `variable_permutation[v]`
+    /// and constraint c is relabeled `constraint_permutation[c]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the permutations are not permutations of the labels of the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let graph = Graph::from_edges(vec![Edge::new(0, 0), Edge::new(1, 0), Edge::new(2, 1)]);
+    /// let permuted = graph.permute(&[2, 0, 1], &[1, 0]);
+    ///
+    /// assert!(permuted.contains_edge(Edge::new(2, 1)));
+    /// assert!(permuted.contains_edge(Edge::new(0, 1)));
+    /// assert!(permuted.contains_edge(Edge::new(1, 0)));
+    /// ```
+    pub fn permute(
+        &self,
+        variable_permutation: &[usize],
+        constraint_permutation: &[usize],
+    ) -> Self {
+        Self::check_permutation(variable_permutation, self.number_of_variables(), "variable");
+        Self::check_permutation(
+            constraint_permutation,
+            self.number_of_constraints(),
+            "constraint",
+        );
+        let mut graph = Self::with_nodes(self.number_of_variables(), self.number_of_constraints());
+        graph.extend(self.edges().map(|edge| {
+            Edge::new(
+                variable_permutation[edge.variable],
+                constraint_permutation[edge.constraint],
+            )
+        }));
+        graph
+    }
+
+    fn check_permutation(permutation: &[usize], number_of_nodes: usize, kind: &str) {
+        assert_eq!(
+            permutation.len(),
+            number_of_nodes,
+            "the {} permutation must have one label per {}",
+            kind,
+            kind
+        );
+        let mut is_used = vec![false; number_of_nodes];
+        for &label in permutation {
+            assert!(
+                label < number_of_nodes && !is_used[label],
+                "the {} permutation is not a permutation",
+                kind
+            );
+            is_used[label] = true;
+        }
+    }
+
+    /// Returns a copy of the graph where variables and constraints are relabeled
+    /// with uniformly random permutations together with the relabeling used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::Graph;
+    /// use rand::thread_rng;
+    ///
+    /// let graph = Graph::from_variable_neighbors(vec![vec![0, 1], vec![1, 2], vec![2]]);
+    /// let (shuffled, relabeling) = graph.shuffle_with(&mut thread_rng());
+    ///
+    /// for edge in graph.edges() {
+    ///     assert!(shuffled.contains_edge(relabeling.apply(edge).unwrap()));
+    /// }
+    /// ```
+    pub fn shuffle_with<R: Rng>(&self, rng: &mut R) -> (Self, Relabeling) {
+        let mut variable_permutation: Vec<usize> = (0..self.number_of_variables()).collect();
+        variable_permutation.shuffle(rng);
+        let mut constraint_permutation: Vec<usize> = (0..self.number_of_constraints()).collect();
+        constraint_permutation.shuffle(rng);
+        let graph = self.permute(&variable_permutation, &constraint_permutation);
+        let relabeling = Relabeling {
+            variables: variable_permutation.into_iter().map(Some).collect(),
+            constraints: constraint_permutation.into_iter().map(Some).collect(),
+        };
+        (graph, relabeling)
+    }
+
+    /// Sorts the neighbors of each node in increasing label order and
+    /// the edges in increasing (variable, constraint) order.
+    ///
+    /// This makes the iteration order of [`edges`](Graph::edges)
+    /// and of the neighbors of [`variables`](Graph::variables)
+    /// and [`constraints`](Graph::constraints) deterministic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::from_edges(vec![Edge::new(1, 0), Edge::new(0, 2), Edge::new(0, 1)]);
+    /// graph.sort_neighbors();
+    ///
+    /// let edges: Vec<Edge> = graph.edges().collect();
+    /// assert_eq!(edges, vec![Edge::new(0, 1), Edge::new(0, 2), Edge::new(1, 0)]);
+    ///
+    /// let neighbors: Vec<usize> = graph.variables().next().unwrap().neighbors().iter().cloned().collect();
+    /// assert_eq!(neighbors, vec![1, 2]);
+    /// ```
+    pub fn sort_neighbors(&mut self) {
+        for neighbors in self
+            .variable_neighbors
+            .iter_mut()
+            .chain(self.constraint_neighbors.iter_mut())
+        {
+            neighbors.sort();
+        }
+        self.edges.sort_by(|first, second| {
+            (first.variable, first.constraint).cmp(&(second.variable, second.constraint))
+        });
+    }
+
     /// Returns an iterator over all edges in the graph in some possibly random order.
     pub fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
         self.edges.iter().cloned()
@@ -232,14 +587,16 @@ impl Graph {
 
     /// Returns the number of variables in the graph.
     ///
-    /// That is, the one more than the highest variable label inserted in the graph.
+    /// That is, the one more than the highest variable label inserted in the graph
+    /// or the number of variables given to [`with_nodes`](Graph::with_nodes) if it is larger.
     pub fn number_of_variables(&self) -> usize {
         self.variable_neighbors.len()
     }
 
     /// Returns the number of constraints in the graph.
     ///
-    /// That is, the one more than the highest constraint label inserted in the graph.
+    /// That is, the one more than the highest constraint label inserted in the graph
+    /// or the number of constraints given to [`with_nodes`](Graph::with_nodes) if it is larger.
     pub fn number_of_constraints(&self) -> usize {
         self.constraint_neighbors.len()
     }
@@ -278,7 +635,7 @@ impl Graph {
     ///
     /// assert!(iter.next().is_none());
     /// ```
-    pub fn variables(&self) -> Nodes {
+    pub fn variables(&self) -> Nodes<'_> {
         Nodes {
             iter: self.variable_neighbors.iter().enumerate(),
             kind: NodeKind::Variable,
@@ -316,13 +673,133 @@ impl Graph {
     /// assert!(iter.next().is_some());
     /// assert!(iter.next().is_none());
     /// ```
-    pub fn constraints(&self) -> Nodes {
+    pub fn constraints(&self) -> Nodes<'_> {
         Nodes {
             iter: self.constraint_neighbors.iter().enumerate(),
             kind: NodeKind::Constraint,
         }
     }
 
+    /// Returns the number of variables and constraints of each degree.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// graph.insert_edge(Edge::new(0, 0));
+    /// graph.insert_edge(Edge::new(0, 1));
+    /// graph.insert_edge(Edge::new(1, 1));
+    ///
+    /// let histogram = graph.degree_histogram();
+    /// assert_eq!(histogram.variables, vec![0, 1, 1]); // One variable of degree 1 and 2.
+    /// assert_eq!(histogram.constraints, vec![0, 1, 1]); // One constraint of degree 1 and 2.
+    /// ```
+    pub fn degree_histogram(&self) -> DegreeHistogram {
+        DegreeHistogram {
+            variables: Self::histogram(&self.variable_neighbors),
+            constraints: Self::histogram(&self.constraint_neighbors),
+        }
+    }
+
+    fn histogram(neighbors: &[IndexSet<usize>]) -> Vec<usize> {
+        let mut histogram = Vec::new();
+        for degree in neighbors.iter().map(IndexSet::len) {
+            if degree >= histogram.len() {
+                histogram.resize(degree + 1, 0);
+            }
+            histogram[degree] += 1;
+        }
+        histogram
+    }
+
+    /// Returns the variable's and constraint's degrees if all variables
+    /// have the same degree and all constraints have the same degree.
+    /// Else, returns None.
+    ///
+    /// A graph without variables or without constraints is never biregular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::complete_graph(3, 2);
+    /// assert_eq!(graph.is_biregular(), Some((2, 3)));
+    ///
+    /// graph.remove_edge(Edge::new(0, 0));
+    /// assert_eq!(graph.is_biregular(), None);
+    /// ```
+    pub fn is_biregular(&self) -> Option<(usize, usize)> {
+        Some((
+            Self::common_degree(&self.variable_neighbors)?,
+            Self::common_degree(&self.constraint_neighbors)?,
+        ))
+    }
+
+    fn common_degree(neighbors: &[IndexSet<usize>]) -> Option<usize> {
+        let degree = neighbors.first()?.len();
+        neighbors
+            .iter()
+            .all(|neighbors| neighbors.len() == degree)
+            .then_some(degree)
+    }
+
+    /// Checks that the edges of the graph agree with the neighbors
+    /// of every variable and every constraint.
+    ///
+    /// Graphs built with the methods of this crate are always valid.
+    /// This is useful for graphs loaded from an external source.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::Graph;
+    ///
+    /// assert!(Graph::complete_graph(3, 4).validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidGraph> {
+        for edge in self.edges() {
+            if edge.variable >= self.number_of_variables()
+                || edge.constraint >= self.number_of_constraints()
+            {
+                return Err(InvalidGraph::EdgeOutOfRange(edge));
+            }
+            if !self.variable_neighbors[edge.variable].contains(&edge.constraint) {
+                return Err(InvalidGraph::MissingVariableNeighbor(edge));
+            }
+            if !self.constraint_neighbors[edge.constraint].contains(&edge.variable) {
+                return Err(InvalidGraph::MissingConstraintNeighbor(edge));
+            }
+        }
+        let variable_edges =
+            self.variable_neighbors
+                .iter()
+                .enumerate()
+                .flat_map(|(variable, neighbors)| {
+                    neighbors
+                        .iter()
+                        .map(move |&constraint| Edge::new(variable, constraint))
+                });
+        let constraint_edges =
+            self.constraint_neighbors
+                .iter()
+                .enumerate()
+                .flat_map(|(constraint, neighbors)| {
+                    neighbors
+                        .iter()
+                        .map(move |&variable| Edge::new(variable, constraint))
+                });
+        match variable_edges
+            .chain(constraint_edges)
+            .find(|edge| !self.contains_edge(*edge))
+        {
+            Some(edge) => Err(InvalidGraph::UnknownEdge(edge)),
+            None => Ok(()),
+        }
+    }
+
     pub(crate) fn from_sampler(sampler: &Sampler) -> Self {
         Self {
             variable_neighbors: vec![
@@ -338,6 +815,56 @@ impl Graph {
     }
 }
 
+/// A map from the old labels to the new labels of the nodes of a graph.
+///
+/// This is returned by the methods that relabel or drop nodes,
+/// such as [`Graph::compact`](Graph::compact).
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Relabeling {
+    /// The new label of each old variable or None if the variable was dropped.
+    pub variables: Vec<Option<usize>>,
+    /// The new label of each old constraint or None if the constraint was dropped.
+    pub constraints: Vec<Option<usize>>,
+}
+
+impl Relabeling {
+    /// Returns the edge between the new labels of the nodes of the given edge
+    /// or None if any of them was dropped.
+    pub fn apply(&self, edge: Edge) -> Option<Edge> {
+        Some(Edge::new(
+            (*self.variables.get(edge.variable)?)?,
+            (*self.constraints.get(edge.constraint)?)?,
+        ))
+    }
+}
+
+impl FromIterator<Edge> for Graph {
+    fn from_iter<I: IntoIterator<Item = Edge>>(edges: I) -> Self {
+        let mut graph = Self::new();
+        graph.extend(edges);
+        graph
+    }
+}
+
+impl Extend<Edge> for Graph {
+    fn extend<I: IntoIterator<Item = Edge>>(&mut self, edges: I) {
+        for edge in edges {
+            self.insert_edge(edge);
+        }
+    }
+}
+
+/// The number of nodes of each degree in a graph.
+///
+/// This is created via the [`Graph::degree_histogram`](Graph::degree_histogram) method.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct DegreeHistogram {
+    /// The number of variables of each degree, indexed by degree.
+    pub variables: Vec<usize>,
+    /// The number of constraints of each degree, indexed by degree.
+    pub constraints: Vec<usize>,
+}
+
 /// An iterator for a set of nodes in a graph.
 ///
 /// This is created via the [`Graph::variables`](Graph::variables)
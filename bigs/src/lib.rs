@@ -28,9 +28,23 @@
 //! let other_graph = sampler.sample_with(&mut thread_rng());
 //! ```
 
+pub mod bits;
 pub mod builder;
+pub mod code;
+pub mod decoder;
+pub mod distance;
 pub mod error;
+pub mod formats;
+pub mod gf2;
 pub mod graph;
+pub mod matrix;
+pub mod operations;
+pub mod serialization;
+pub mod simulation;
+pub mod substructures;
+pub mod syndrome;
+pub mod threshold;
+pub mod xorsat;
 
 mod sampler;
 pub use crate::sampler::Sampler;
@@ -0,0 +1,262 @@
+//! Binary matrices and the parity-check matrix of a graph.
+//!
+//! The parity-check matrix of a graph has one row per constraint
+//! and one column per variable.
+//! The entry at row c and column v is 1 if there is an edge between
+//! variable v and constraint c, and 0 otherwise.
+//!
+//! It can be obtained either as a [`DenseMatrix`](DenseMatrix)
+//! or as a [`SparseMatrix`](SparseMatrix).
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::graph::{Edge, Graph};
+//!
+//! let mut graph = Graph::new();
+//! graph.insert_edge(Edge::new(0, 0));
+//! graph.insert_edge(Edge::new(2, 0));
+//! graph.insert_edge(Edge::new(1, 1));
+//!
+//! let dense = graph.parity_check_matrix();
+//! assert!(dense.get(0, 0));
+//! assert!(!dense.get(0, 1));
+//! assert!(dense.get(0, 2));
+//! assert!(dense.get(1, 1));
+//!
+//! let sparse = graph.sparse_parity_check_matrix();
+//! assert_eq!(sparse.row(0), &[0, 2]);
+//! assert_eq!(sparse.row(1), &[1]);
+//! ```
+
+use crate::graph::Graph;
+
+pub(crate) const WORD_SIZE: usize = u64::BITS as usize;
+
+/// A bit-packed binary matrix stored in row-major order.
+///
+/// Each row is stored in a sequence of `u64` words.
+/// The entry at column j of a row is the bit `j % 64` of the word `j / 64`.
+/// The unused bits of the last word of each row are always 0.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct DenseMatrix {
+    number_of_rows: usize,
+    number_of_columns: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl DenseMatrix {
+    /// Creates a matrix of the given shape filled with zeros.
+    pub fn zeros(number_of_rows: usize, number_of_columns: usize) -> Self {
+        let words_per_row = number_of_columns.div_ceil(WORD_SIZE);
+        Self {
+            number_of_rows,
+            number_of_columns,
+            words_per_row,
+            words: vec![0; number_of_rows * words_per_row],
+        }
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn number_of_rows(&self) -> usize {
+        self.number_of_rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn number_of_columns(&self) -> usize {
+        self.number_of_columns
+    }
+
+    /// Returns the entry at the given row and column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row or the column is out of bound.
+    pub fn get(&self, row: usize, column: usize) -> bool {
+        self.check_bounds(row, column);
+        self.words[self.word_index(row, column)] >> (column % WORD_SIZE) & 1 == 1
+    }
+
+    /// Sets the entry at the given row and column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row or the column is out of bound.
+    pub fn set(&mut self, row: usize, column: usize, value: bool) {
+        self.check_bounds(row, column);
+        let index = self.word_index(row, column);
+        let mask = 1 << (column % WORD_SIZE);
+        if value {
+            self.words[index] |= mask;
+        } else {
+            self.words[index] &= !mask;
+        }
+    }
+
+    /// Returns the words storing the given row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bound.
+    pub fn row_words(&self, row: usize) -> &[u64] {
+        assert!(row < self.number_of_rows, "row {} is out of bound", row);
+        &self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    /// Returns all the words of the matrix, one row after the other.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns the number of ones in the given row.
+    pub fn row_weight(&self, row: usize) -> usize {
+        self.row_words(row)
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Adds the source row to the target row, that is, xors them.
+    pub(crate) fn add_row(&mut self, source: usize, target: usize) {
+        for word in 0..self.words_per_row {
+            self.words[target * self.words_per_row + word] ^=
+                self.words[source * self.words_per_row + word];
+        }
+    }
+
+    pub(crate) fn swap_rows(&mut self, first: usize, second: usize) {
+        for word in 0..self.words_per_row {
+            self.words.swap(
+                first * self.words_per_row + word,
+                second * self.words_per_row + word,
+            );
+        }
+    }
+
+    pub(crate) fn truncate_rows(&mut self, number_of_rows: usize) {
+        self.number_of_rows = self.number_of_rows.min(number_of_rows);
+        self.words
+            .truncate(self.number_of_rows * self.words_per_row);
+    }
+
+    fn word_index(&self, row: usize, column: usize) -> usize {
+        row * self.words_per_row + column / WORD_SIZE
+    }
+
+    fn check_bounds(&self, row: usize, column: usize) {
+        assert!(
+            row < self.number_of_rows && column < self.number_of_columns,
+            "entry ({}, {}) is out of bound for a {} x {} matrix",
+            row,
+            column,
+            self.number_of_rows,
+            self.number_of_columns
+        );
+    }
+}
+
+/// A binary matrix stored in compressed sparse row (CSR) format.
+///
+/// Only the positions of the ones are stored.
+/// The column indices of the ones in row r are
+/// `column_indices[row_offsets[r]..row_offsets[r + 1]]`
+/// in increasing order.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct SparseMatrix {
+    number_of_rows: usize,
+    number_of_columns: usize,
+    row_offsets: Vec<usize>,
+    column_indices: Vec<usize>,
+}
+
+impl SparseMatrix {
+    /// Returns the number of rows in the matrix.
+    pub fn number_of_rows(&self) -> usize {
+        self.number_of_rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn number_of_columns(&self) -> usize {
+        self.number_of_columns
+    }
+
+    /// Returns the number of ones in the matrix.
+    pub fn number_of_nonzeros(&self) -> usize {
+        self.column_indices.len()
+    }
+
+    /// Returns the row offsets of the matrix.
+    ///
+    /// This has one more element than the number of rows.
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    /// Returns the column indices of the ones, one row after the other.
+    pub fn column_indices(&self) -> &[usize] {
+        &self.column_indices
+    }
+
+    /// Returns the column indices of the ones in the given row in increasing order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bound.
+    pub fn row(&self, row: usize) -> &[usize] {
+        &self.column_indices[self.row_offsets[row]..self.row_offsets[row + 1]]
+    }
+
+    /// Returns an iterator over the (row, column) coordinates of the ones
+    /// in row-major order.
+    ///
+    /// This is the triplet (or coordinate) format where all values are implicitly 1.
+    pub fn coordinates(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.number_of_rows)
+            .flat_map(move |row| self.row(row).iter().map(move |&column| (row, column)))
+    }
+
+    /// Converts the matrix to a dense matrix.
+    pub fn to_dense(&self) -> DenseMatrix {
+        let mut matrix = DenseMatrix::zeros(self.number_of_rows, self.number_of_columns);
+        for (row, column) in self.coordinates() {
+            matrix.set(row, column, true);
+        }
+        matrix
+    }
+}
+
+impl Graph {
+    /// Returns the parity-check matrix of the graph as a dense matrix.
+    ///
+    /// Rows are constraints and columns are variables.
+    pub fn parity_check_matrix(&self) -> DenseMatrix {
+        let mut matrix =
+            DenseMatrix::zeros(self.number_of_constraints(), self.number_of_variables());
+        for edge in self.edges() {
+            matrix.set(edge.constraint, edge.variable, true);
+        }
+        matrix
+    }
+
+    /// Returns the parity-check matrix of the graph as a sparse matrix.
+    ///
+    /// Rows are constraints and columns are variables.
+    pub fn sparse_parity_check_matrix(&self) -> SparseMatrix {
+        let mut row_offsets = Vec::with_capacity(self.number_of_constraints() + 1);
+        let mut column_indices = Vec::with_capacity(self.number_of_edges());
+        row_offsets.push(0);
+        for constraint in self.constraints() {
+            let start = column_indices.len();
+            column_indices.extend(constraint.neighbors().iter());
+            column_indices[start..].sort_unstable();
+            row_offsets.push(column_indices.len());
+        }
+        SparseMatrix {
+            number_of_rows: self.number_of_constraints(),
+            number_of_columns: self.number_of_variables(),
+            row_offsets,
+            column_indices,
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Operations building new graphs from existing ones.
+//!
+//! These are the building blocks of code constructions on top of sampled graphs:
+//! swapping the roles of variables and constraints, putting graphs side by side,
+//! extracting the subgraph induced by some nodes and computing the 2-core.
+
+use crate::graph::{Edge, Graph, Relabeling};
+
+impl Graph {
+    /// Returns the graph where variables become constraints and constraints become variables.
+    ///
+    /// Variable v becomes constraint v and constraint c becomes variable c.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let graph = Graph::from_edges(vec![Edge::new(0, 1), Edge::new(2, 1)]);
+    /// let transpose = graph.transpose();
+    ///
+    /// assert_eq!(transpose.number_of_variables(), 2);
+    /// assert_eq!(transpose.number_of_constraints(), 3);
+    /// assert!(transpose.contains_edge(Edge::new(1, 0)));
+    /// assert!(transpose.contains_edge(Edge::new(1, 2)));
+    /// assert_eq!(transpose.transpose(), graph);
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let mut graph = Self::with_nodes(self.number_of_constraints(), self.number_of_variables());
+        graph.extend(
+            self.edges()
+                .map(|edge| Edge::new(edge.constraint, edge.variable)),
+        );
+        graph
+    }
+
+    /// Returns the disjoint union of the graph with another graph.
+    ///
+    /// The nodes of this graph keep their labels while the labels of the
+    /// nodes of the other graph are offset by the number of nodes of this graph.
+    /// That is, variable v of the other graph becomes variable
+    /// v + number of variables of this graph and likewise for constraints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let graph = Graph::complete_graph(2, 1);
+    /// let other = Graph::from_edges(vec![Edge::new(0, 1)]);
+    /// let union = graph.disjoint_union(&other);
+    ///
+    /// assert_eq!(union.number_of_variables(), 3);
+    /// assert_eq!(union.number_of_constraints(), 3);
+    /// assert_eq!(union.number_of_edges(), 3);
+    /// assert!(union.contains_edge(Edge::new(2, 2)));
+    /// ```
+    pub fn disjoint_union(&self, other: &Graph) -> Self {
+        let variable_offset = self.number_of_variables();
+        let constraint_offset = self.number_of_constraints();
+        let mut graph = self.clone();
+        graph.variable_neighbors.extend(
+            other
+                .variable_neighbors
+                .iter()
+                .map(|neighbors| neighbors.iter().map(|c| c + constraint_offset).collect()),
+        );
+        graph.constraint_neighbors.extend(
+            other
+                .constraint_neighbors
+                .iter()
+                .map(|neighbors| neighbors.iter().map(|v| v + variable_offset).collect()),
+        );
+        graph.edges.extend(other.edges().map(|edge| {
+            Edge::new(
+                edge.variable + variable_offset,
+                edge.constraint + constraint_offset,
+            )
+        }));
+        graph
+    }
+
+    /// Returns the subgraph induced by the given variables and constraints
+    /// together with the map from the labels of the graph to the labels of the subgraph.
+    ///
+    /// The i-th given variable becomes variable i of the subgraph
+    /// and likewise for constraints.
+    /// The subgraph contains all edges of the graph between given nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a label is out of range or is given twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let graph = Graph::complete_graph(4, 3);
+    /// let (subgraph, relabeling) = graph.induced_subgraph(&[3, 1], &[2]);
+    ///
+    /// assert_eq!(subgraph.number_of_variables(), 2);
+    /// assert_eq!(subgraph.number_of_constraints(), 1);
+    /// assert_eq!(subgraph.number_of_edges(), 2);
+    /// assert_eq!(relabeling.variables, vec![None, Some(1), None, Some(0)]);
+    /// assert_eq!(relabeling.apply(Edge::new(3, 2)), Some(Edge::new(0, 0)));
+    /// ```
+    pub fn induced_subgraph(
+        &self,
+        variables: &[usize],
+        constraints: &[usize],
+    ) -> (Self, Relabeling) {
+        let relabeling = Relabeling {
+            variables: Self::subset_labels(variables, self.number_of_variables(), "variable"),
+            constraints: Self::subset_labels(
+                constraints,
+                self.number_of_constraints(),
+                "constraint",
+            ),
+        };
+        let mut graph = Self::with_nodes(variables.len(), constraints.len());
+        for &variable in variables {
+            graph.extend(
+                self.variable_neighbors[variable]
+                    .iter()
+                    .filter_map(|&constraint| relabeling.apply(Edge::new(variable, constraint))),
+            );
+        }
+        (graph, relabeling)
+    }
+
+    /// Returns the 2-core of the graph together with the map from the labels
+    /// of the graph to the labels of the core.
+    ///
+    /// The core is obtained by repeatedly removing a variable with at most one neighbor
+    /// together with its neighbor, and finally removing constraints without neighbors.
+    /// In the core, every variable has at least 2 neighbors.
+    /// Nodes keep their relative order.
+    ///
+    /// For a XORSAT instance, the removed constraints can always be satisfied
+    /// by fixing their removed variables last,
+    /// so the instance is satisfiable if and only if its restriction to the core is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// // A cycle of length 4 with a pendant path through constraint 2 and variable 2.
+    /// let graph = Graph::from_variable_neighbors(vec![vec![0, 1], vec![0, 1, 2], vec![2]]);
+    /// let (core, relabeling) = graph.two_core();
+    ///
+    /// assert_eq!(core, Graph::complete_graph(2, 2));
+    /// assert_eq!(relabeling.variables, vec![Some(0), Some(1), None]);
+    /// assert_eq!(relabeling.constraints, vec![Some(0), Some(1), None]);
+    /// ```
+    pub fn two_core(&self) -> (Self, Relabeling) {
+        let mut degrees: Vec<usize> = self.variables().map(|variable| variable.degree()).collect();
+        let mut is_variable_removed = vec![false; self.number_of_variables()];
+        let mut is_constraint_removed = vec![false; self.number_of_constraints()];
+        let mut leaves: Vec<usize> = (0..self.number_of_variables())
+            .filter(|&variable| degrees[variable] <= 1)
+            .collect();
+        while let Some(variable) = leaves.pop() {
+            if is_variable_removed[variable] {
+                continue;
+            }
+            is_variable_removed[variable] = true;
+            for &constraint in self.variable_neighbors[variable].iter() {
+                if is_constraint_removed[constraint] {
+                    continue;
+                }
+                is_constraint_removed[constraint] = true;
+                for &neighbor in self.constraint_neighbors[constraint].iter() {
+                    degrees[neighbor] -= 1;
+                    if degrees[neighbor] == 1 {
+                        leaves.push(neighbor);
+                    }
+                }
+            }
+        }
+        let variables: Vec<usize> = (0..self.number_of_variables())
+            .filter(|&variable| !is_variable_removed[variable])
+            .collect();
+        let constraints: Vec<usize> = (0..self.number_of_constraints())
+            .filter(|&constraint| {
+                !is_constraint_removed[constraint]
+                    && !self.constraint_neighbors[constraint].is_empty()
+            })
+            .collect();
+        self.induced_subgraph(&variables, &constraints)
+    }
+
+    fn subset_labels(subset: &[usize], number_of_nodes: usize, kind: &str) -> Vec<Option<usize>> {
+        let mut labels = vec![None; number_of_nodes];
+        for (new_label, &label) in subset.iter().enumerate() {
+            assert!(
+                label < number_of_nodes,
+                "{} {} is out of range",
+                kind,
+                label
+            );
+            assert!(labels[label].is_none(), "{} {} is given twice", kind, label);
+            labels[label] = Some(new_label);
+        }
+        labels
+    }
+}
@@ -198,7 +198,7 @@ impl<'s> Sample<'s> {
     }
 
     fn try_to_swap_edge_and_insert(&mut self, graph: &mut Graph, edge: Edge) {
-        if let Some(edge_to_swap) = Self::find_edge_to_swap(edge, &graph) {
+        if let Some(edge_to_swap) = Self::find_edge_to_swap(edge, graph) {
             graph.remove_edge(edge_to_swap);
             let (first_swapped_edge, second_swapped_edge) = Self::swap(edge, edge_to_swap);
             graph.insert_edge(first_swapped_edge);
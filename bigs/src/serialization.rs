@@ -0,0 +1,157 @@
+//! The serde representation of graphs.
+//!
+//! A graph is serialized as a map with the fields
+//! - `version`: the version of the representation, currently 1,
+//! - `number_of_variables`: the number of variables,
+//! - `number_of_constraints`: the number of constraints,
+//! - `variable_neighbors`: for each variable, the labels of its neighbors in increasing order.
+//!
+//! For example, in JSON, the graph with an edge between variable 0 and constraint 1
+//! and an edge between variable 1 and constraint 1 is
+//! ```json
+//! {
+//!     "version": 1,
+//!     "number_of_variables": 2,
+//!     "number_of_constraints": 2,
+//!     "variable_neighbors": [[1], [1]]
+//! }
+//! ```
+//!
+//! Deserialization checks that the number of neighbor lists is the number of variables
+//! and that the neighbors of each variable are distinct labels of existing constraints.
+//!
+//! The representation of bigs 0.3, without a version and where the internal
+//! `variable_neighbors`, `constraint_neighbors` and `edges` fields are written,
+//! can still be deserialized as long as these fields agree with each other.
+
+use crate::graph::{Edge, Graph};
+use indexmap::IndexSet;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct SerializedGraph {
+    version: u32,
+    number_of_variables: usize,
+    number_of_constraints: usize,
+    variable_neighbors: Vec<Vec<usize>>,
+}
+
+#[derive(Deserialize)]
+struct DeserializedGraph {
+    version: Option<u32>,
+    number_of_variables: Option<usize>,
+    number_of_constraints: Option<usize>,
+    variable_neighbors: Vec<Vec<usize>>,
+    constraint_neighbors: Option<Vec<Vec<usize>>>,
+    edges: Option<Vec<Edge>>,
+}
+
+impl Serialize for Graph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let variable_neighbors = self
+            .variables()
+            .map(|variable| {
+                let mut neighbors: Vec<usize> = variable.neighbors().iter().cloned().collect();
+                neighbors.sort_unstable();
+                neighbors
+            })
+            .collect();
+        SerializedGraph {
+            version: VERSION,
+            number_of_variables: self.number_of_variables(),
+            number_of_constraints: self.number_of_constraints(),
+            variable_neighbors,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Graph {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let graph = DeserializedGraph::deserialize(deserializer)?;
+        match graph.version {
+            Some(VERSION) => from_version_1(graph).map_err(D::Error::custom),
+            Some(version) => Err(D::Error::custom(format!(
+                "unsupported graph version {}, expected {}",
+                version, VERSION
+            ))),
+            None => from_legacy(graph).map_err(D::Error::custom),
+        }
+    }
+}
+
+fn from_version_1(graph: DeserializedGraph) -> Result<Graph, String> {
+    let number_of_variables = graph
+        .number_of_variables
+        .ok_or("missing field `number_of_variables`")?;
+    let number_of_constraints = graph
+        .number_of_constraints
+        .ok_or("missing field `number_of_constraints`")?;
+    if graph.variable_neighbors.len() != number_of_variables {
+        return Err(format!(
+            "expected neighbors for {} variables, found {}",
+            number_of_variables,
+            graph.variable_neighbors.len()
+        ));
+    }
+    // The number of constraints is untrusted, so the edges are checked before allocating.
+    let mut edges = Vec::new();
+    for (variable, neighbors) in graph.variable_neighbors.into_iter().enumerate() {
+        for constraint in neighbors {
+            if constraint >= number_of_constraints {
+                return Err(format!(
+                    "variable {} is a neighbor of constraint {}, but there are only {} constraints",
+                    variable, constraint, number_of_constraints
+                ));
+            }
+            edges.push(Edge::new(variable, constraint));
+        }
+    }
+    let mut deserialized = Graph::try_with_nodes(number_of_variables, number_of_constraints)
+        .ok_or_else(|| format!("can't allocate {} constraints", number_of_constraints))?;
+    for edge in edges {
+        if !deserialized.insert_edge(edge) {
+            return Err(format!(
+                "constraint {} appears twice in the neighbors of variable {}",
+                edge.constraint, edge.variable
+            ));
+        }
+    }
+    Ok(deserialized)
+}
+
+fn from_legacy(graph: DeserializedGraph) -> Result<Graph, String> {
+    let constraint_neighbors = graph
+        .constraint_neighbors
+        .ok_or("missing field `version`")?;
+    let edges = graph.edges.ok_or("missing field `edges`")?;
+    let number_of_edges = edges.len();
+    let mut deserialized = Graph::new();
+    deserialized.variable_neighbors = into_sets(graph.variable_neighbors)?;
+    deserialized.constraint_neighbors = into_sets(constraint_neighbors)?;
+    deserialized.edges = edges.into_iter().collect();
+    if deserialized.number_of_edges() != number_of_edges {
+        return Err("an edge appears twice".to_string());
+    }
+    deserialized.validate().map_err(|error| error.to_string())?;
+    Ok(deserialized)
+}
+
+fn into_sets(neighbors: Vec<Vec<usize>>) -> Result<Vec<IndexSet<usize>>, String> {
+    neighbors
+        .into_iter()
+        .enumerate()
+        .map(|(label, neighbors)| {
+            let length = neighbors.len();
+            let set: IndexSet<usize> = neighbors.into_iter().collect();
+            if set.len() == length {
+                Ok(set)
+            } else {
+                Err(format!("node {} has a repeated neighbor", label))
+            }
+        })
+        .collect()
+}
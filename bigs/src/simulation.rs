@@ -0,0 +1,281 @@
+//! Monte-Carlo estimation of the error rates of decoders.
+//!
+//! A [`Simulation`](Simulation) repeatedly sends the all-zero codeword of the code
+//! defined by a graph through a [`Channel`](Channel), decodes the output
+//! and counts the frames and the bits which are not recovered.
+//! Since the code is linear and the channels are symmetric,
+//! this gives the same error rates as sending random codewords
+//! with decoders which are symmetric, such as the ones of this crate.
+//! Variables which the decoder reports as undetermined count as bit errors
+//! whatever their value in the decoded word.
+//!
+//! Simulations are reproducible: each trial draws its noise from its own random
+//! number generator seeded from the seed of the simulation and the index of the trial,
+//! and trials are counted in order.
+//! Thus, the result only depends on the seed, not on the number of threads.
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::decoder::BeliefPropagation;
+//! use bigs::simulation::{Channel, Simulation};
+//! use bigs::Sampler;
+//! use rand::thread_rng;
+//!
+//! let sampler = Sampler::builder()
+//!     .number_of_variables(100)
+//!     .number_of_constraints(50)
+//!     .variable_degree(3)
+//!     .constraint_degree(6)
+//!     .build()
+//!     .unwrap();
+//! let graph = sampler.sample_with(&mut thread_rng());
+//!
+//! let rates = Simulation::new(&graph, Channel::BinarySymmetric(0.02))
+//!     .max_trials(1000)
+//!     .target_frame_errors(10)
+//!     .seed(42)
+//!     .run(BeliefPropagation::new);
+//!
+//! assert!(rates.trials <= 1000);
+//! assert!(rates.frame_error_rate() <= 1.0);
+//! ```
+
+use crate::decoder::Decoder;
+use crate::graph::Graph;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::PI;
+
+// The number of trials done by each thread between two checks of the stopping condition.
+const TRIALS_PER_THREAD: usize = 64;
+
+/// A binary-input memoryless symmetric channel.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Channel {
+    /// The binary symmetric channel flipping each bit with the given probability.
+    BinarySymmetric(f64),
+    /// The binary erasure channel erasing each bit with the given probability.
+    BinaryErasure(f64),
+    /// The additive white Gaussian noise channel with BPSK modulation
+    /// and the given noise standard deviation.
+    Awgn(f64),
+}
+
+impl Channel {
+    /// Returns the LLRs of the output of the channel when the all-zero word
+    /// of the given length is sent.
+    ///
+    /// Erased bits have an LLR of 0 and unerased bits an infinite LLR.
+    pub fn transmit_zeros_with<R: Rng>(&self, length: usize, rng: &mut R) -> Vec<f64> {
+        match *self {
+            Channel::BinarySymmetric(probability) => {
+                let llr = ((1.0 - probability) / probability).ln();
+                (0..length)
+                    .map(|_| if rng.gen_bool(probability) { -llr } else { llr })
+                    .collect()
+            }
+            Channel::BinaryErasure(probability) => (0..length)
+                .map(|_| {
+                    if rng.gen_bool(probability) {
+                        0.0
+                    } else {
+                        f64::INFINITY
+                    }
+                })
+                .collect(),
+            Channel::Awgn(standard_deviation) => {
+                let scale = 2.0 / (standard_deviation * standard_deviation);
+                (0..length)
+                    .map(|_| scale * (1.0 + standard_deviation * standard_normal(rng)))
+                    .collect()
+            }
+        }
+    }
+}
+
+// Samples a standard normal random variable with the Box-Muller transform.
+fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let (uniform, angle): (f64, f64) = (rng.gen(), rng.gen());
+    (-2.0 * (1.0 - uniform).ln()).sqrt() * (2.0 * PI * angle).cos()
+}
+
+/// The estimated error rates of a simulation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ErrorRates {
+    /// The number of frames sent.
+    pub trials: u64,
+    /// The number of frames which were not decoded to the sent codeword.
+    pub frame_errors: u64,
+    /// The number of bits which were not decoded to the sent value
+    /// or which the decoder reported as undetermined.
+    pub bit_errors: u64,
+    /// The number of bits in each frame.
+    pub bits_per_frame: usize,
+    /// The total number of iterations of the decoder.
+    pub iterations: u64,
+}
+
+impl ErrorRates {
+    /// Returns the fraction of frames which were not decoded correctly.
+    pub fn frame_error_rate(&self) -> f64 {
+        self.frame_errors as f64 / self.trials as f64
+    }
+
+    /// Returns the fraction of bits which were not decoded correctly.
+    pub fn bit_error_rate(&self) -> f64 {
+        self.bit_errors as f64 / (self.trials as f64 * self.bits_per_frame as f64)
+    }
+
+    /// Returns the average number of iterations of the decoder per frame.
+    pub fn average_iterations(&self) -> f64 {
+        self.iterations as f64 / self.trials as f64
+    }
+}
+
+/// A Monte-Carlo simulation of a decoder on a channel.
+///
+/// The simulation stops as soon as the target number of frame errors is reached
+/// or after the maximum number of trials.
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone)]
+pub struct Simulation<'g> {
+    graph: &'g Graph,
+    channel: Channel,
+    max_trials: u64,
+    target_frame_errors: u64,
+    seed: u64,
+    number_of_threads: usize,
+}
+
+impl<'g> Simulation<'g> {
+    /// Creates a simulation of the code defined by the given graph on the given channel.
+    pub fn new(graph: &'g Graph, channel: Channel) -> Self {
+        Self {
+            graph,
+            channel,
+            max_trials: 10_000,
+            target_frame_errors: 100,
+            seed: 0,
+            number_of_threads: std::thread::available_parallelism()
+                .map(|threads| threads.get())
+                .unwrap_or(1),
+        }
+    }
+
+    /// Fixes the maximum number of frames sent. Default is 10000.
+    pub fn max_trials(&mut self, trials: u64) -> &mut Self {
+        self.max_trials = trials;
+        self
+    }
+
+    /// Fixes the number of frame errors after which the simulation stops. Default is 100.
+    pub fn target_frame_errors(&mut self, errors: u64) -> &mut Self {
+        self.target_frame_errors = errors;
+        self
+    }
+
+    /// Fixes the seed of the random number generators. Default is 0.
+    pub fn seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Fixes the number of threads running trials.
+    /// Default is the available parallelism of the machine.
+    pub fn number_of_threads(&mut self, threads: usize) -> &mut Self {
+        self.number_of_threads = threads.max(1);
+        self
+    }
+
+    /// Runs the simulation with decoders created from the graph
+    /// by the given function, one per thread.
+    pub fn run<D, F>(&self, new_decoder: F) -> ErrorRates
+    where
+        D: Decoder + Send,
+        F: Fn(&Graph) -> D,
+    {
+        let threads = self.number_of_threads;
+        let mut decoders: Vec<D> = (0..threads).map(|_| new_decoder(self.graph)).collect();
+        let mut rates = ErrorRates {
+            trials: 0,
+            frame_errors: 0,
+            bit_errors: 0,
+            bits_per_frame: self.graph.number_of_variables(),
+            iterations: 0,
+        };
+        while rates.trials < self.max_trials && rates.frame_errors < self.target_frame_errors {
+            let start = rates.trials;
+            let end = self
+                .max_trials
+                .min(start + (threads * TRIALS_PER_THREAD) as u64);
+            let outcomes: Vec<Vec<Outcome>> = std::thread::scope(|scope| {
+                let handles: Vec<_> = decoders
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(thread, decoder)| {
+                        scope.spawn(move || {
+                            (start + thread as u64..end)
+                                .step_by(threads)
+                                .map(|trial| self.run_trial(decoder, trial))
+                                .collect()
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle
+                            .join()
+                            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                    })
+                    .collect()
+            });
+            // Trials are counted in order so that the result
+            // does not depend on the number of threads.
+            for index in 0..(end - start) as usize {
+                let outcome = outcomes[index % threads][index / threads];
+                rates.trials += 1;
+                rates.bit_errors += outcome.bit_errors as u64;
+                rates.frame_errors += (outcome.bit_errors > 0) as u64;
+                rates.iterations += outcome.iterations as u64;
+                if rates.frame_errors >= self.target_frame_errors {
+                    break;
+                }
+            }
+        }
+        rates
+    }
+
+    fn run_trial<D: Decoder>(&self, decoder: &mut D, trial: u64) -> Outcome {
+        let mut rng = StdRng::seed_from_u64(trial_seed(self.seed, trial));
+        let llrs = self
+            .channel
+            .transmit_zeros_with(self.graph.number_of_variables(), &mut rng);
+        let decoding = decoder.decode(&llrs);
+        Outcome {
+            bit_errors: decoding
+                .word
+                .iter()
+                .zip(decoding.undetermined.iter())
+                .filter(|&(bit, is_undetermined)| bit || is_undetermined)
+                .count(),
+            iterations: decoding.iterations,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Outcome {
+    bit_errors: usize,
+    iterations: usize,
+}
+
+// Mixes the seed of the simulation and the index of a trial with the
+// SplitMix64 finalizer so that nearby seeds give unrelated trials.
+fn trial_seed(seed: u64, trial: u64) -> u64 {
+    let mut z = seed ^ trial.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,279 @@
+//! Search for small harmful substructures: stopping sets and trapping sets.
+//!
+//! When a graph is used as the Tanner graph of an LDPC code,
+//! small stopping sets and trapping sets dominate the error floor of iterative decoders.
+//! The searches in this module are exhaustive up to a given size,
+//! so they are only practical for small sizes.
+
+use crate::graph::Graph;
+use indexmap::IndexSet;
+
+/// An (a, b) trapping set.
+///
+/// That is, a set of a variables such that exactly b constraints
+/// are connected an odd number of times to the set.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct TrappingSet {
+    /// The labels of the variables in the set in increasing order.
+    pub variables: Vec<usize>,
+    /// The number of constraints connected an odd number of times to the variables.
+    pub number_of_odd_constraints: usize,
+}
+
+impl TrappingSet {
+    /// Returns the number of variables in the set.
+    pub fn size(&self) -> usize {
+        self.variables.len()
+    }
+}
+
+impl Graph {
+    /// Returns all minimal stopping sets with at most `max_size` variables.
+    ///
+    /// A stopping set is a non-empty set of variables such that every constraint
+    /// connected to the set is connected to it at least twice.
+    /// It is minimal if none of its proper subsets is a stopping set.
+    /// Every stopping set is a union of minimal ones.
+    ///
+    /// The sets are sorted by size and then lexicographically.
+    /// Each set contains variable labels in increasing order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// let mut graph = Graph::new();
+    /// // Variables 0 and 1 are both connected to constraints 0 and 1.
+    /// graph.insert_edge(Edge::new(0, 0));
+    /// graph.insert_edge(Edge::new(0, 1));
+    /// graph.insert_edge(Edge::new(1, 0));
+    /// graph.insert_edge(Edge::new(1, 1));
+    /// // Variable 2 is the only one connected to constraint 2.
+    /// graph.insert_edge(Edge::new(2, 1));
+    /// graph.insert_edge(Edge::new(2, 2));
+    ///
+    /// assert_eq!(graph.stopping_sets(3), vec![vec![0, 1]]);
+    /// assert!(graph.stopping_sets(1).is_empty());
+    /// ```
+    pub fn stopping_sets(&self, max_size: usize) -> Vec<Vec<usize>> {
+        let mut search = Search::new(self);
+        let mut found = IndexSet::new();
+        if max_size > 0 {
+            for variable in 0..self.number_of_variables() {
+                search.insert(variable);
+                search.extend_stopping_set(max_size, &mut found);
+                search.remove(variable);
+            }
+        }
+        let mut sets: Vec<Vec<usize>> = found.into_iter().collect();
+        sets.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        let mut minimal_sets: Vec<Vec<usize>> = Vec::with_capacity(sets.len());
+        for set in sets {
+            if !minimal_sets.iter().any(|smaller| is_subset(smaller, &set)) {
+                minimal_sets.push(set);
+            }
+        }
+        minimal_sets
+    }
+
+    /// Returns all connected (a, b) trapping sets with a at most `max_size`
+    /// and b at most `max_odd_constraints`.
+    ///
+    /// A set of variables is connected if any two of its variables are linked by
+    /// a path going only through variables of the set and their constraints.
+    /// Every trapping set is a disjoint union of connected ones.
+    ///
+    /// The sets are sorted by size, then by number of odd constraints
+    /// and then lexicographically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::{Edge, Graph};
+    ///
+    /// // A 4-cycle: variables 0 and 1 both connected to constraints 0 and 1.
+    /// let graph = Graph::complete_graph(2, 2);
+    ///
+    /// let sets = graph.trapping_sets(2, 0);
+    /// assert_eq!(sets.len(), 1);
+    /// assert_eq!(sets[0].variables, vec![0, 1]);
+    /// assert_eq!(sets[0].number_of_odd_constraints, 0);
+    ///
+    /// // Each variable alone is a (1, 2) trapping set.
+    /// assert_eq!(graph.trapping_sets(1, 2).len(), 2);
+    /// ```
+    pub fn trapping_sets(&self, max_size: usize, max_odd_constraints: usize) -> Vec<TrappingSet> {
+        let mut search = Search::new(self);
+        let mut found = Vec::new();
+        if max_size > 0 {
+            for variable in 0..self.number_of_variables() {
+                search.insert(variable);
+                let extension = search.exclusive_neighbors(variable, variable);
+                search.extend_trapping_set(
+                    variable,
+                    extension,
+                    max_size,
+                    max_odd_constraints,
+                    &mut found,
+                );
+                search.remove(variable);
+            }
+        }
+        found.sort_by(|a: &TrappingSet, b| {
+            a.size()
+                .cmp(&b.size())
+                .then_with(|| {
+                    a.number_of_odd_constraints
+                        .cmp(&b.number_of_odd_constraints)
+                })
+                .then_with(|| a.variables.cmp(&b.variables))
+        });
+        found
+    }
+}
+
+// Checks if the sorted slice `a` is a subset of the sorted slice `b`.
+fn is_subset(a: &[usize], b: &[usize]) -> bool {
+    let mut b = b.iter();
+    a.iter().all(|x| b.any(|y| y == x))
+}
+
+// The state of a search: a set of variables together with the number of
+// times each constraint is connected to it.
+struct Search<'g> {
+    graph: &'g Graph,
+    variables: Vec<usize>,
+    constraint_counts: Vec<usize>,
+    number_of_odd_constraints: usize,
+}
+
+impl<'g> Search<'g> {
+    fn new(graph: &'g Graph) -> Self {
+        Self {
+            graph,
+            variables: Vec::new(),
+            constraint_counts: vec![0; graph.number_of_constraints()],
+            number_of_odd_constraints: 0,
+        }
+    }
+
+    fn insert(&mut self, variable: usize) {
+        self.variables.push(variable);
+        for &constraint in self.graph.variable_neighbors[variable].iter() {
+            self.constraint_counts[constraint] += 1;
+            self.update_odd_constraints(constraint);
+        }
+    }
+
+    fn remove(&mut self, variable: usize) {
+        self.variables.retain(|&other| other != variable);
+        for &constraint in self.graph.variable_neighbors[variable].iter() {
+            self.constraint_counts[constraint] -= 1;
+            self.update_odd_constraints(constraint);
+        }
+    }
+
+    fn update_odd_constraints(&mut self, constraint: usize) {
+        if self.constraint_counts[constraint] % 2 == 1 {
+            self.number_of_odd_constraints += 1;
+        } else {
+            self.number_of_odd_constraints -= 1;
+        }
+    }
+
+    fn contains(&self, variable: usize) -> bool {
+        self.variables.contains(&variable)
+    }
+
+    fn sorted_variables(&self) -> Vec<usize> {
+        let mut variables = self.variables.clone();
+        variables.sort_unstable();
+        variables
+    }
+
+    fn dangling_constraint(&self) -> Option<usize> {
+        self.variables.iter().find_map(|&variable| {
+            self.graph.variable_neighbors[variable]
+                .iter()
+                .find(|&&constraint| self.constraint_counts[constraint] == 1)
+                .cloned()
+        })
+    }
+
+    // Every stopping set containing the current variables must contain another
+    // neighbor of each dangling constraint. Since the first variable is the smallest
+    // in the set, only variables with larger labels are added.
+    fn extend_stopping_set(&mut self, max_size: usize, found: &mut IndexSet<Vec<usize>>) {
+        let dangling_constraint = match self.dangling_constraint() {
+            Some(constraint) => constraint,
+            None => {
+                found.insert(self.sorted_variables());
+                return;
+            }
+        };
+        if self.variables.len() >= max_size {
+            return;
+        }
+        let smallest = self.variables[0];
+        for &variable in self.graph.constraint_neighbors[dangling_constraint].iter() {
+            if variable > smallest && !self.contains(variable) {
+                self.insert(variable);
+                self.extend_stopping_set(max_size, found);
+                self.remove(variable);
+            }
+        }
+    }
+
+    fn is_adjacent(&self, variable: usize) -> bool {
+        self.graph.variable_neighbors[variable]
+            .iter()
+            .any(|&constraint| self.constraint_counts[constraint] > 0)
+    }
+
+    // The variables sharing a constraint with the given variable that are larger than
+    // the root, not in the set and not adjacent to the set before inserting the variable.
+    fn exclusive_neighbors(&self, variable: usize, root: usize) -> Vec<usize> {
+        let mut neighbors = IndexSet::new();
+        for &constraint in self.graph.variable_neighbors[variable].iter() {
+            for &neighbor in self.graph.constraint_neighbors[constraint].iter() {
+                if neighbor > root && !self.contains(neighbor) {
+                    neighbors.insert(neighbor);
+                }
+            }
+        }
+        neighbors.into_iter().collect()
+    }
+
+    // Enumerates each connected set with smallest variable `root` exactly once
+    // (this is the ESU algorithm of Wernicke).
+    fn extend_trapping_set(
+        &mut self,
+        root: usize,
+        mut extension: Vec<usize>,
+        max_size: usize,
+        max_odd_constraints: usize,
+        found: &mut Vec<TrappingSet>,
+    ) {
+        if self.number_of_odd_constraints <= max_odd_constraints {
+            found.push(TrappingSet {
+                variables: self.sorted_variables(),
+                number_of_odd_constraints: self.number_of_odd_constraints,
+            });
+        }
+        if self.variables.len() >= max_size {
+            return;
+        }
+        while let Some(variable) = extension.pop() {
+            let mut next_extension = extension.clone();
+            next_extension.extend(
+                self.exclusive_neighbors(variable, root)
+                    .into_iter()
+                    .filter(|&neighbor| !self.is_adjacent(neighbor)),
+            );
+            self.insert(variable);
+            self.extend_trapping_set(root, next_extension, max_size, max_odd_constraints, found);
+            self.remove(variable);
+        }
+    }
+}
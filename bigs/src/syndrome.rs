@@ -0,0 +1,118 @@
+//! Syndrome computation.
+//!
+//! The syndrome of an assignment of the variables has one bit per constraint
+//! which is the parity of the values of the neighbors of the constraint.
+//! An assignment is a codeword if and only if its syndrome is zero.
+//!
+//! All syndromes read the word 64 variables at a time from its bit-packed words.
+//! [`Graph::syndrome`](Graph::syndrome) and [`SparseMatrix::syndrome`](SparseMatrix::syndrome)
+//! take a time proportional to the number of edges.
+//! The sparse matrix is slightly faster since it stores the neighbors in contiguous memory,
+//! which matters when computing many syndromes with the same graph, as decoders do.
+//! [`DenseMatrix::syndrome`](DenseMatrix::syndrome) takes a time proportional to
+//! the number of constraints times the number of variables divided by 64,
+//! so it is only worth it when the matrix is already at hand.
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::bits::BitVec;
+//! use bigs::graph::{Edge, Graph};
+//!
+//! let graph = Graph::from_edges(vec![Edge::new(0, 0), Edge::new(1, 0), Edge::new(1, 1)]);
+//! let word: BitVec = vec![true, false].into_iter().collect();
+//!
+//! let syndrome = graph.syndrome(&word);
+//! assert_eq!(syndrome.iter().collect::<Vec<_>>(), vec![true, false]);
+//! assert_eq!(graph.sparse_parity_check_matrix().syndrome(&word), syndrome);
+//! assert_eq!(graph.parity_check_matrix().syndrome(&word), syndrome);
+//! ```
+
+use crate::bits::BitVec;
+use crate::graph::Graph;
+use crate::matrix::{DenseMatrix, SparseMatrix, WORD_SIZE};
+
+impl Graph {
+    /// Returns the syndrome of the given assignment of the variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the word is not the number of variables.
+    pub fn syndrome(&self, word: &BitVec) -> BitVec {
+        check_length(word, self.number_of_variables());
+        let words = word.words();
+        syndrome_from_parities(
+            self.number_of_constraints(),
+            self.constraint_neighbors
+                .iter()
+                .map(|neighbors| parity(neighbors.iter(), words)),
+        )
+    }
+}
+
+impl SparseMatrix {
+    /// Returns the product of the matrix with the given vector over GF(2).
+    ///
+    /// When the matrix is a parity-check matrix, this is the syndrome of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the vector is not the number of columns.
+    pub fn syndrome(&self, word: &BitVec) -> BitVec {
+        check_length(word, self.number_of_columns());
+        let words = word.words();
+        syndrome_from_parities(
+            self.number_of_rows(),
+            (0..self.number_of_rows()).map(|row| parity(self.row(row).iter(), words)),
+        )
+    }
+}
+
+impl DenseMatrix {
+    /// Returns the product of the matrix with the given vector over GF(2).
+    ///
+    /// When the matrix is a parity-check matrix, this is the syndrome of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the vector is not the number of columns.
+    pub fn syndrome(&self, word: &BitVec) -> BitVec {
+        check_length(word, self.number_of_columns());
+        syndrome_from_parities(
+            self.number_of_rows(),
+            (0..self.number_of_rows()).map(|row| {
+                self.row_words(row)
+                    .iter()
+                    .zip(word.words())
+                    .fold(0, |parity, (row_word, word)| parity ^ (row_word & word))
+                    .count_ones()
+                    % 2
+                    == 1
+            }),
+        )
+    }
+}
+
+fn syndrome_from_parities<I: Iterator<Item = bool>>(len: usize, parities: I) -> BitVec {
+    let mut words = vec![0; len.div_ceil(WORD_SIZE)];
+    for (position, parity) in parities.enumerate() {
+        words[position / WORD_SIZE] |= (parity as u64) << (position % WORD_SIZE);
+    }
+    BitVec::from_words(len, words)
+}
+
+// The parity of the bits of the word at the given positions.
+fn parity<'a, I: Iterator<Item = &'a usize>>(positions: I, words: &[u64]) -> bool {
+    positions.fold(0, |parity, &position| {
+        parity ^ (words[position / WORD_SIZE] >> (position % WORD_SIZE))
+    }) & 1
+        == 1
+}
+
+fn check_length(word: &BitVec, number_of_variables: usize) {
+    assert_eq!(
+        word.len(),
+        number_of_variables,
+        "the word must have one bit per variable"
+    );
+}
@@ -0,0 +1,156 @@
+//! Decoding thresholds of the regular ensembles of samplers.
+//!
+//! The graphs of a sampler are drawn from the regular (dv, dc) ensemble,
+//! where dv is the variable degree and dc the constraint degree.
+//! As the number of variables grows, belief propagation on a random graph of the ensemble
+//! succeeds with high probability if and only if the channel is less noisy than the threshold
+//! computed by density evolution.
+//! This is the reference to compare the finite-length performance of sampled graphs with.
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::Sampler;
+//!
+//! let sampler = Sampler::builder()
+//!     .number_of_variables(1000)
+//!     .number_of_constraints(500)
+//!     .variable_degree(3)
+//!     .constraint_degree(6)
+//!     .build()
+//!     .unwrap();
+//!
+//! assert!((sampler.bec_threshold() - 0.4294).abs() < 1e-4);
+//! assert!((sampler.awgn_threshold() - 0.8747).abs() < 1e-3);
+//! ```
+
+use crate::sampler::Sampler;
+
+// The precision of the thresholds.
+const TOLERANCE: f64 = 1e-7;
+// The maximum number of density evolution iterations.
+const MAX_ITERATIONS: usize = 20_000;
+// Once the mean of the messages is this large, they go to infinity.
+const CONVERGED_MEAN: f64 = 200.0;
+
+impl Sampler {
+    /// Returns the erasure probability threshold of belief propagation
+    /// on the binary erasure channel for the ensemble of the sampler.
+    ///
+    /// This is the largest erasure probability ε such that the erasure probability
+    /// of the messages, following the recursion
+    /// `x ← ε (1 - (1 - x)^(dc - 1))^(dv - 1)` from `x = ε`, goes to 0.
+    /// It is 0 if the variable degree is at most 1 and 1 if the constraint degree is 1.
+    pub fn bec_threshold(&self) -> f64 {
+        let (variable_degree, constraint_degree) = (self.variable_degree, self.constraint_degree);
+        if variable_degree <= 1 || constraint_degree == 0 {
+            return 0.0;
+        }
+        bisect(0.0, 1.0, |erasure_probability| {
+            let mut erasure = erasure_probability;
+            for _ in 0..MAX_ITERATIONS {
+                let next = erasure_probability
+                    * (1.0 - (1.0 - erasure).powi(constraint_degree as i32 - 1))
+                        .powi(variable_degree as i32 - 1);
+                if next < TOLERANCE * TOLERANCE {
+                    return true;
+                }
+                if (erasure - next).abs() < TOLERANCE * TOLERANCE {
+                    return false;
+                }
+                erasure = next;
+            }
+            false
+        })
+    }
+
+    /// Returns the noise standard deviation threshold of belief propagation
+    /// on the additive white Gaussian noise channel with BPSK modulation
+    /// for the ensemble of the sampler.
+    ///
+    /// This uses the Gaussian approximation of density evolution,
+    /// which assumes that messages are Gaussian and tracks their means.
+    /// It is usually within 1% of the exact threshold.
+    /// The corresponding signal-to-noise ratio per information bit of a code of rate r
+    /// is `Eb/N0 = 1 / (2 r σ²)`.
+    /// It is 0 if the variable degree is at most 1.
+    pub fn awgn_threshold(&self) -> f64 {
+        let (variable_degree, constraint_degree) = (self.variable_degree, self.constraint_degree);
+        if variable_degree <= 1 || constraint_degree == 0 {
+            return 0.0;
+        }
+        // The capacity of the channel is negligible for standard deviations close to 100.
+        bisect(0.0, 100.0, |standard_deviation| {
+            let channel_mean = 2.0 / (standard_deviation * standard_deviation);
+            let mut constraint_mean: f64 = 0.0;
+            for _ in 0..MAX_ITERATIONS {
+                let variable_mean = channel_mean + (variable_degree - 1) as f64 * constraint_mean;
+                // 1 - (1 - φ(m))^(dc - 1) computed without cancellation.
+                let constraint_phi =
+                    -((constraint_degree - 1) as f64 * (-phi(variable_mean)).ln_1p()).exp_m1();
+                let next = phi_inverse(constraint_phi);
+                if next > CONVERGED_MEAN {
+                    return true;
+                }
+                if (next - constraint_mean).abs() < TOLERANCE * TOLERANCE {
+                    return false;
+                }
+                constraint_mean = next;
+            }
+            false
+        })
+    }
+}
+
+// Returns the largest value in the interval for which the predicate is true,
+// assuming that it is true below some value and false above.
+fn bisect<F: Fn(f64) -> bool>(mut low: f64, mut high: f64, is_below: F) -> f64 {
+    while high - low > TOLERANCE {
+        let middle = (low + high) / 2.0;
+        if is_below(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / 2.0
+}
+
+// The function φ(m) = 1 - E[tanh(u / 2)] where u is Gaussian with mean m and variance 2m,
+// using the approximation of Chung, Richardson and Urbanke.
+fn phi(mean: f64) -> f64 {
+    if mean <= 0.0 {
+        1.0
+    } else if mean < 10.0 {
+        (-0.4527 * mean.powf(0.86) + 0.0218).exp().min(1.0)
+    } else {
+        (std::f64::consts::PI / mean).sqrt() * (-mean / 4.0).exp() * (1.0 - 10.0 / (7.0 * mean))
+    }
+}
+
+// The inverse of φ, which is decreasing.
+fn phi_inverse(value: f64) -> f64 {
+    if value >= 1.0 {
+        return 0.0;
+    }
+    if value <= 0.0 {
+        return f64::INFINITY;
+    }
+    let mut high = 1.0;
+    while phi(high) > value {
+        high *= 2.0;
+        if high > CONVERGED_MEAN * 2.0 {
+            return high;
+        }
+    }
+    let (mut low, mut high) = (0.0, high);
+    for _ in 0..60 {
+        let middle = (low + high) / 2.0;
+        if phi(middle) > value {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / 2.0
+}
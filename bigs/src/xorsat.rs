@@ -0,0 +1,180 @@
+//! Random XORSAT instances.
+//!
+//! A XORSAT instance is a system of linear equations modulo 2.
+//! Each constraint of a graph is an equation stating that the sum
+//! of its neighbor variables is equal to its parity.
+//!
+//! Random regular instances are sampled with the
+//! [`sample_xorsat_with`](crate::Sampler::sample_xorsat_with)
+//! and [`sample_planted_xorsat_with`](crate::Sampler::sample_planted_xorsat_with)
+//! methods of a [`Sampler`](crate::Sampler).
+//!
+//! # Example
+//!
+//! ```
+//! use bigs::Sampler;
+//! use rand::thread_rng;
+//!
+//! let sampler = Sampler::builder()
+//!     .number_of_variables(20)
+//!     .number_of_constraints(15)
+//!     .variable_degree(3)
+//!     .constraint_degree(4)
+//!     .build()
+//!     .unwrap();
+//!
+//! let (instance, solution) = sampler.sample_planted_xorsat_with(&mut thread_rng());
+//! assert!(instance.is_satisfied_by(&solution));
+//! ```
+
+use crate::graph::Graph;
+use crate::Sampler;
+use rand::Rng;
+use std::io::Write;
+
+/// A XORSAT instance.
+///
+/// That is, a graph together with a parity for each constraint.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct XorSat {
+    graph: Graph,
+    parities: Vec<bool>,
+}
+
+impl XorSat {
+    /// Creates an instance from a graph and the parity of each constraint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of parities is not the number of constraints in the graph.
+    pub fn new(graph: Graph, parities: Vec<bool>) -> Self {
+        assert_eq!(
+            graph.number_of_constraints(),
+            parities.len(),
+            "there must be one parity per constraint"
+        );
+        Self { graph, parities }
+    }
+
+    /// Returns the graph of the instance.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the parity of each constraint.
+    pub fn parities(&self) -> &[bool] {
+        &self.parities
+    }
+
+    /// Checks if the sum of the neighbors of each constraint
+    /// is equal to its parity for the given assignment of the variables.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the assignment is shorter than the number of variables.
+    pub fn is_satisfied_by(&self, assignment: &[bool]) -> bool {
+        self.graph
+            .constraints()
+            .zip(self.parities.iter())
+            .all(|(constraint, &parity)| {
+                let sum = constraint
+                    .neighbors()
+                    .iter()
+                    .fold(false, |sum, &variable| sum ^ assignment[variable]);
+                sum == parity
+            })
+    }
+
+    /// Writes the instance in the CNF-XOR format of CryptoMiniSat.
+    ///
+    /// The header is the one of the DIMACS CNF format.
+    /// Then each constraint becomes a line starting with `x`
+    /// followed by its neighbor variables as 1-based DIMACS literals.
+    /// Such a line states that the exclusive or of its literals is true.
+    /// Thus, when the parity of a constraint is 0,
+    /// its first literal is negated.
+    /// Constraints without neighbors and of parity 0 are always satisfied and are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bigs::graph::Graph;
+    /// use bigs::xorsat::XorSat;
+    ///
+    /// let instance = XorSat::new(Graph::complete_graph(2, 2), vec![true, false]);
+    ///
+    /// let mut cnf = Vec::new();
+    /// instance.write_cnf_xor(&mut cnf).unwrap();
+    /// assert_eq!(String::from_utf8(cnf).unwrap(), "p cnf 2 2\nx1 2 0\nx-1 2 0\n");
+    /// ```
+    pub fn write_cnf_xor<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        let clauses: Vec<Vec<i64>> = self
+            .graph
+            .constraints()
+            .zip(self.parities.iter())
+            .filter(|(constraint, &parity)| constraint.degree() > 0 || parity)
+            .map(|(constraint, &parity)| {
+                let mut literals: Vec<i64> = constraint
+                    .neighbors()
+                    .iter()
+                    .map(|&variable| variable as i64 + 1)
+                    .collect();
+                literals.sort_unstable();
+                if !parity {
+                    literals[0] = -literals[0];
+                }
+                literals
+            })
+            .collect();
+        writeln!(
+            writer,
+            "p cnf {} {}",
+            self.graph.number_of_variables(),
+            clauses.len()
+        )?;
+        for literals in clauses {
+            write!(writer, "x")?;
+            for literal in literals {
+                write!(writer, "{} ", literal)?;
+            }
+            writeln!(writer, "0")?;
+        }
+        Ok(())
+    }
+}
+
+impl Sampler {
+    /// Samples a random XORSAT instance where the graph is sampled
+    /// with the sampler parameters and the parities are uniformly random.
+    ///
+    /// Such an instance may not have any solution.
+    pub fn sample_xorsat_with<R: Rng>(&self, rng: &mut R) -> XorSat {
+        let graph = self.sample_with(rng);
+        let parities = (0..graph.number_of_constraints())
+            .map(|_| rng.gen())
+            .collect();
+        XorSat::new(graph, parities)
+    }
+
+    /// Samples a random XORSAT instance together with one of its solutions.
+    ///
+    /// The graph is sampled with the sampler parameters
+    /// and the solution is a uniformly random assignment.
+    /// Then, the parities are chosen such that the solution satisfies all constraints.
+    pub fn sample_planted_xorsat_with<R: Rng>(&self, rng: &mut R) -> (XorSat, Vec<bool>) {
+        let graph = self.sample_with(rng);
+        let solution: Vec<bool> = (0..graph.number_of_variables())
+            .map(|_| rng.gen())
+            .collect();
+        let parities = graph
+            .constraints()
+            .map(|constraint| {
+                constraint
+                    .neighbors()
+                    .iter()
+                    .fold(false, |sum, &variable| sum ^ solution[variable])
+            })
+            .collect();
+        (XorSat::new(graph, parities), solution)
+    }
+}
@@ -1,6 +1,17 @@
+use bigs::bits::BitVec;
+use bigs::code::Code;
+use bigs::decoder::{
+    awgn_llrs, bsc_llrs, BeliefPropagation, BitFlipping, Decoder, Peeling, SipserSpielman,
+    UpdateRule,
+};
+use bigs::error::{InvalidGraph, ReadError};
+use bigs::formats::{BinaryReader, BinaryWriter};
+use bigs::graph::{Edge, Graph};
+use bigs::simulation::{Channel, Simulation};
 use bigs::Sampler;
 use rand::rngs::SmallRng;
 use rand::{thread_rng, Rng, SeedableRng};
+use std::f64::consts::PI;
 
 const NUMBER_OF_RANDOM_TESTS: u32 = 10;
 
@@ -61,3 +72,999 @@ fn error_if_parameters_do_not_fit() {
         .build();
     assert!(sampler.is_err());
 }
+
+#[test]
+fn stopping_sets_match_exhaustive_search() {
+    let sampler = Sampler::builder()
+        .number_of_variables(8)
+        .number_of_constraints(6)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let graph = sampler.sample_with(&mut thread_rng());
+        let is_stopping_set = |set: u32| {
+            set != 0
+                && graph.constraints().all(|constraint| {
+                    constraint
+                        .neighbors()
+                        .iter()
+                        .filter(|&&variable| set & (1 << variable) != 0)
+                        .count()
+                        != 1
+                })
+        };
+        let stopping_sets: Vec<u32> = (0..1 << 8).filter(|&set| is_stopping_set(set)).collect();
+        let mut minimal_sets: Vec<Vec<usize>> = stopping_sets
+            .iter()
+            .filter(|&&set| {
+                set.count_ones() <= 5
+                    && stopping_sets
+                        .iter()
+                        .all(|&other| other == set || other & set != other)
+            })
+            .map(|&set| {
+                (0..8)
+                    .filter(|variable| set & (1 << variable) != 0)
+                    .collect()
+            })
+            .collect();
+        minimal_sets.sort_by(|a: &Vec<usize>, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        assert_eq!(graph.stopping_sets(5), minimal_sets);
+    }
+}
+
+#[test]
+fn trapping_sets_are_connected_and_unique() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let sets = graph.trapping_sets(3, usize::MAX);
+    let mut variables: Vec<&Vec<usize>> = sets.iter().map(|set| &set.variables).collect();
+    variables.dedup();
+    assert_eq!(variables.len(), sets.len());
+    assert_eq!(sets.iter().filter(|set| set.size() == 1).count(), 12);
+    for set in sets.iter().filter(|set| set.size() == 1) {
+        assert_eq!(set.number_of_odd_constraints, 3);
+    }
+    let number_of_connected_pairs = (0..12)
+        .flat_map(|first| (first + 1..12).map(move |second| (first, second)))
+        .filter(|&(first, second)| {
+            graph
+                .constraints()
+                .any(|constraint| constraint.has_neighbor(first) && constraint.has_neighbor(second))
+        })
+        .count();
+    assert_eq!(
+        sets.iter().filter(|set| set.size() == 2).count(),
+        number_of_connected_pairs
+    );
+}
+
+#[test]
+fn sampled_graphs_are_valid_and_biregular() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let graph = sampler.sample_with(&mut thread_rng());
+        assert!(graph.validate().is_ok());
+        assert_eq!(graph.is_biregular(), Some((3, 4)));
+        let histogram = graph.degree_histogram();
+        assert_eq!(histogram.variables, vec![0, 0, 0, 12]);
+        assert_eq!(histogram.constraints, vec![0, 0, 0, 0, 9]);
+    }
+}
+
+#[test]
+fn inconsistent_graphs_are_not_deserialized() {
+    let legacy = r#"{
+        "variable_neighbors": [[0], [0]],
+        "constraint_neighbors": [[0, 1]],
+        "edges": [{"variable": 0, "constraint": 0}]
+    }"#;
+    let error = serde_json::from_str::<Graph>(legacy).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains(&InvalidGraph::UnknownEdge(Edge::new(1, 0)).to_string()));
+
+    let out_of_range = r#"{
+        "version": 1,
+        "number_of_variables": 2,
+        "number_of_constraints": 1,
+        "variable_neighbors": [[0], [1]]
+    }"#;
+    assert!(serde_json::from_str::<Graph>(out_of_range).is_err());
+
+    let unknown_version = r#"{
+        "version": 2,
+        "number_of_variables": 0,
+        "number_of_constraints": 0,
+        "variable_neighbors": []
+    }"#;
+    assert!(serde_json::from_str::<Graph>(unknown_version).is_err());
+
+    let too_many_constraints = r#"{
+        "version": 1,
+        "number_of_variables": 0,
+        "number_of_constraints": 4611686018427387904,
+        "variable_neighbors": []
+    }"#;
+    assert!(serde_json::from_str::<Graph>(too_many_constraints).is_err());
+}
+
+#[test]
+fn serde_round_trip() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let json = serde_json::to_string(&graph).unwrap();
+    let other_graph: Graph = serde_json::from_str(&json).unwrap();
+    assert_eq!(other_graph.number_of_edges(), 36);
+    assert!(graph.edges().all(|edge| other_graph.contains_edge(edge)));
+
+    let legacy = r#"{
+        "variable_neighbors": [[0], [0]],
+        "constraint_neighbors": [[1, 0]],
+        "edges": [{"variable": 0, "constraint": 0}, {"variable": 1, "constraint": 0}]
+    }"#;
+    let graph: Graph = serde_json::from_str(legacy).unwrap();
+    assert_eq!(graph.number_of_edges(), 2);
+}
+
+#[test]
+fn dense_and_sparse_parity_check_matrices_agree() {
+    let sampler = Sampler::builder()
+        .number_of_variables(70)
+        .number_of_constraints(42)
+        .variable_degree(3)
+        .constraint_degree(5)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let dense = graph.parity_check_matrix();
+    let sparse = graph.sparse_parity_check_matrix();
+    assert_eq!(dense.number_of_rows(), 42);
+    assert_eq!(dense.number_of_columns(), 70);
+    assert_eq!(sparse.number_of_nonzeros(), 210);
+    assert_eq!(sparse.to_dense(), dense);
+    for edge in graph.edges() {
+        assert!(dense.get(edge.constraint, edge.variable));
+    }
+    for row in 0..42 {
+        assert_eq!(dense.row_weight(row), 5);
+    }
+}
+
+#[test]
+fn alist_round_trip() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let graph = sampler.sample_with(&mut thread_rng());
+        let mut alist = Vec::new();
+        graph.write_alist(&mut alist).unwrap();
+        let other_graph = Graph::read_alist(alist.as_slice()).unwrap();
+        assert_eq!(other_graph.number_of_variables(), 12);
+        assert_eq!(other_graph.number_of_constraints(), 9);
+        assert_eq!(other_graph.number_of_edges(), 36);
+        assert!(graph.edges().all(|edge| other_graph.contains_edge(edge)));
+    }
+}
+
+#[test]
+fn malformed_alist_are_rejected() {
+    let inconsistent = "2 2\n2 2\n1 2\n2 1\n1 0\n1 2\n1 2\n1 0\n";
+    let error = Graph::read_alist(inconsistent.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 8, .. }));
+
+    let out_of_range = "2 2\n2 2\n1 2\n2 1\n3 0\n1 2\n1 2\n2 0\n";
+    let error = Graph::read_alist(out_of_range.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 5, .. }));
+
+    let repeated_variable = "1 1\n1 2\n1\n2\n1\n1 1\n";
+    let error = Graph::read_alist(repeated_variable.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 6, .. }));
+
+    let not_a_number = "2 2\n2 x\n";
+    let error = Graph::read_alist(not_a_number.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 2, .. }));
+}
+
+#[test]
+fn random_dimacs_cnf_is_reproducible() {
+    let sampler = Sampler::builder()
+        .number_of_variables(20)
+        .number_of_constraints(40)
+        .variable_degree(6)
+        .constraint_degree(3)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let seed = thread_rng().gen();
+    let mut cnf = Vec::new();
+    let mut other_cnf = Vec::new();
+    graph
+        .write_random_dimacs_cnf(&mut cnf, &mut SmallRng::seed_from_u64(seed))
+        .unwrap();
+    graph
+        .write_random_dimacs_cnf(&mut other_cnf, &mut SmallRng::seed_from_u64(seed))
+        .unwrap();
+    assert_eq!(cnf, other_cnf);
+
+    let cnf = String::from_utf8(cnf).unwrap();
+    let mut lines = cnf.lines();
+    assert_eq!(lines.next(), Some("p cnf 20 40"));
+    for (constraint, clause) in graph.constraints().zip(lines) {
+        let literals: Vec<i64> = clause
+            .split_whitespace()
+            .map(|literal| literal.parse().unwrap())
+            .collect();
+        assert_eq!(literals.len(), 4);
+        assert_eq!(literals[3], 0);
+        for literal in &literals[..3] {
+            assert!(constraint.has_neighbor(literal.unsigned_abs() as usize - 1));
+        }
+    }
+}
+
+#[test]
+fn planted_xorsat_solutions_satisfy_all_constraints() {
+    let sampler = Sampler::builder()
+        .number_of_variables(30)
+        .number_of_constraints(18)
+        .variable_degree(3)
+        .constraint_degree(5)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let (instance, solution) = sampler.sample_planted_xorsat_with(&mut thread_rng());
+        assert!(instance.is_satisfied_by(&solution));
+        for variable in 0..30 {
+            let mut other_solution = solution.clone();
+            other_solution[variable] = !other_solution[variable];
+            assert!(!instance.is_satisfied_by(&other_solution));
+        }
+    }
+}
+
+#[test]
+fn dot_and_graphml_contain_all_nodes_and_edges() {
+    let graph = Graph::complete_graph(3, 2);
+
+    let mut dot = Vec::new();
+    graph.write_dot(&mut dot).unwrap();
+    let dot = String::from_utf8(dot).unwrap();
+    assert_eq!(dot.matches("shape=circle").count(), 3);
+    assert_eq!(dot.matches("shape=box").count(), 2);
+    assert_eq!(dot.matches(" -- ").count(), 6);
+
+    let mut graphml = Vec::new();
+    graph.write_graphml(&mut graphml).unwrap();
+    let graphml = String::from_utf8(graphml).unwrap();
+    assert_eq!(graphml.matches(">variable<").count(), 3);
+    assert_eq!(graphml.matches(">constraint<").count(), 2);
+    assert_eq!(graphml.matches("<edge ").count(), 6);
+}
+
+#[test]
+fn matrix_market_and_npy_have_the_right_sizes() {
+    let graph = Graph::complete_graph(3, 2);
+
+    let mut mtx = Vec::new();
+    graph.write_matrix_market(&mut mtx).unwrap();
+    let mtx = String::from_utf8(mtx).unwrap();
+    let mut lines = mtx.lines().skip(1);
+    assert_eq!(lines.next(), Some("2 3 6"));
+    assert_eq!(lines.count(), 6);
+
+    let mut npy = Vec::new();
+    graph.write_npy(&mut npy).unwrap();
+    let header_length = u16::from_le_bytes([npy[8], npy[9]]) as usize;
+    assert_eq!((10 + header_length) % 64, 0);
+    assert_eq!(&npy[10 + header_length..], &[1; 6]);
+}
+
+#[test]
+fn binary_round_trip() {
+    let sampler = Sampler::builder()
+        .number_of_variables(300)
+        .number_of_constraints(200)
+        .variable_degree(4)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    let graphs: Vec<Graph> = (0..NUMBER_OF_RANDOM_TESTS)
+        .map(|_| sampler.sample_with(&mut thread_rng()))
+        .collect();
+    let mut writer = BinaryWriter::new(Vec::new()).unwrap();
+    for graph in graphs.iter() {
+        writer.write(graph).unwrap();
+    }
+    let bytes = writer.finish().unwrap();
+    let other_graphs = BinaryReader::new(bytes.as_slice())
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(other_graphs.len(), graphs.len());
+    for (graph, other_graph) in graphs.iter().zip(other_graphs.iter()) {
+        assert_eq!(other_graph.number_of_variables(), 300);
+        assert_eq!(other_graph.number_of_constraints(), 200);
+        assert!(graph.edges().all(|edge| other_graph.contains_edge(edge)));
+        assert_eq!(graph.number_of_edges(), other_graph.number_of_edges());
+    }
+
+    let truncated = &bytes[..bytes.len() - 1];
+    let results: Vec<_> = BinaryReader::new(truncated).unwrap().collect();
+    assert!(matches!(
+        results.last(),
+        Some(Err(ReadError::Corrupted { .. }))
+    ));
+
+    // 2^62 variables of degree 0 and no constraints.
+    let mut huge_header = b"BIGS\x01".to_vec();
+    huge_header.extend([0x80; 8]);
+    huge_header.extend([0x40, 0, 1, 0]);
+    let error = Graph::read_binary(huge_header.as_slice()).unwrap_err();
+    assert!(matches!(error, ReadError::Corrupted { offset: 5, .. }));
+
+    // 2^62 variables with a degree each, but no degree at all.
+    huge_header[15] = 0;
+    huge_header.truncate(16);
+    let error = Graph::read_binary(huge_header.as_slice()).unwrap_err();
+    assert!(matches!(error, ReadError::Corrupted { offset: 16, .. }));
+
+    let truncated_header = b"BIGS\x01\x03";
+    let error = Graph::read_binary(&truncated_header[..]).unwrap_err();
+    assert!(matches!(error, ReadError::Corrupted { offset: 6, .. }));
+}
+
+#[test]
+fn edge_list_round_trip_and_errors() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let mut edges = Vec::new();
+    graph.write_edge_list(&mut edges).unwrap();
+    let other_graph = Graph::read_edge_list(edges.as_slice()).unwrap();
+    assert_eq!(other_graph.number_of_edges(), 36);
+    assert!(graph.edges().all(|edge| other_graph.contains_edge(edge)));
+
+    let out_of_range = "p 2 2\n0 1\n2 0\n";
+    let error = Graph::read_edge_list(out_of_range.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 3, .. }));
+
+    let late_header = "0 1\np 2 2\n";
+    let error = Graph::read_edge_list(late_header.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 2, .. }));
+
+    let huge_header = "p 4611686018427387904 1\n";
+    let error = Graph::read_edge_list(huge_header.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 1, .. }));
+
+    let huge_label = "0 4611686018427387904\n";
+    let error = Graph::read_edge_list(huge_label.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 1, .. }));
+
+    let too_many_labels = "0 1 2\n";
+    let error = Graph::read_edge_list(too_many_labels.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { line: 1, .. }));
+}
+
+#[test]
+fn node_link_round_trip_and_errors() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let mut json = Vec::new();
+    graph.write_node_link(&mut json).unwrap();
+    let other_graph = Graph::read_node_link(json.as_slice()).unwrap();
+    assert_eq!(other_graph.number_of_variables(), 12);
+    assert_eq!(other_graph.number_of_constraints(), 9);
+    assert!(graph.edges().all(|edge| other_graph.contains_edge(edge)));
+    assert_eq!(other_graph.number_of_edges(), 36);
+
+    let same_side = r#"{
+        "nodes": [{"id": 0, "bipartite": 0}, {"id": 1, "bipartite": 0}],
+        "edges": [{"source": 0, "target": 1}]
+    }"#;
+    let error = Graph::read_node_link(same_side.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Invalid(_)));
+
+    let missing_side = r#"{"nodes": [{"id": 0}], "links": []}"#;
+    let error = Graph::read_node_link(missing_side.as_bytes()).unwrap_err();
+    assert!(matches!(error, ReadError::Malformed { .. }));
+}
+
+#[test]
+fn graphs_built_from_edges_or_neighbors_agree() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let from_edges = Graph::from_edges(graph.edges());
+    let from_neighbors = Graph::from_variable_neighbors(
+        graph
+            .variables()
+            .map(|variable| variable.neighbors().iter().cloned().collect())
+            .collect(),
+    );
+    let mut extended = Graph::with_nodes(12, 9);
+    extended.extend(graph.edges());
+    for other_graph in [from_edges, from_neighbors, extended] {
+        assert_eq!(other_graph.number_of_variables(), 12);
+        assert_eq!(other_graph.number_of_constraints(), 9);
+        assert_eq!(other_graph.number_of_edges(), 36);
+        assert!(graph.edges().all(|edge| other_graph.contains_edge(edge)));
+    }
+}
+
+#[test]
+fn isolated_nodes_survive_round_trips() {
+    let mut graph = Graph::with_nodes(4, 3);
+    graph.insert_edge(Edge::new(0, 0));
+
+    let mut alist = Vec::new();
+    graph.write_alist(&mut alist).unwrap();
+    assert_eq!(Graph::read_alist(alist.as_slice()).unwrap(), graph);
+
+    let mut edges = Vec::new();
+    graph.write_edge_list(&mut edges).unwrap();
+    assert_eq!(Graph::read_edge_list(edges.as_slice()).unwrap(), graph);
+
+    let mut bytes = Vec::new();
+    graph.write_binary(&mut bytes).unwrap();
+    assert_eq!(Graph::read_binary(bytes.as_slice()).unwrap(), graph);
+
+    let mut json = Vec::new();
+    graph.write_node_link(&mut json).unwrap();
+    assert_eq!(Graph::read_node_link(json.as_slice()).unwrap(), graph);
+
+    let json = serde_json::to_string(&graph).unwrap();
+    assert_eq!(serde_json::from_str::<Graph>(&json).unwrap(), graph);
+}
+
+#[test]
+fn compacting_drops_removed_nodes_only() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let mut punctured = graph.clone();
+    let relabeling = punctured.compact(&[3, 7], &[0]);
+
+    assert_eq!(punctured.number_of_variables(), 10);
+    assert_eq!(punctured.number_of_constraints(), 8);
+    assert!(punctured.validate().is_ok());
+    let expected_edges: Vec<Edge> = graph
+        .edges()
+        .filter_map(|edge| relabeling.apply(edge))
+        .collect();
+    assert_eq!(punctured.number_of_edges(), expected_edges.len());
+    assert!(expected_edges
+        .iter()
+        .all(|&edge| punctured.contains_edge(edge)));
+    assert_eq!(relabeling.variables[8], Some(6));
+    assert_eq!(relabeling.constraints[1], Some(0));
+}
+
+#[test]
+fn punctured_graphs_equal_their_round_trips() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let mut punctured = graph.clone();
+    punctured.remove_variable(7).unwrap();
+    punctured.remove_variable(3).unwrap();
+    punctured.remove_constraint(0).unwrap();
+    let mut compacted = graph.clone();
+    compacted.compact(&[3, 7], &[0]);
+    assert_eq!(punctured, compacted);
+
+    let mut rebuilt = Graph::with_nodes(10, 8);
+    rebuilt.extend(punctured.edges());
+    assert_eq!(rebuilt, punctured);
+
+    let json = serde_json::to_string(&punctured).unwrap();
+    assert_eq!(serde_json::from_str::<Graph>(&json).unwrap(), punctured);
+
+    let mut bytes = Vec::new();
+    punctured.write_binary(&mut bytes).unwrap();
+    assert_eq!(Graph::read_binary(bytes.as_slice()).unwrap(), punctured);
+}
+
+#[test]
+fn shuffling_preserves_the_structure() {
+    let sampler = Sampler::builder()
+        .number_of_variables(12)
+        .number_of_constraints(9)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let seed = thread_rng().gen();
+    let (shuffled, relabeling) = graph.shuffle_with(&mut SmallRng::seed_from_u64(seed));
+    let (other_shuffled, _) = graph.shuffle_with(&mut SmallRng::seed_from_u64(seed));
+    assert_eq!(shuffled, other_shuffled);
+    assert_eq!(shuffled.is_biregular(), Some((3, 4)));
+    assert_eq!(shuffled.number_of_edges(), 36);
+    for edge in graph.edges() {
+        assert!(shuffled.contains_edge(relabeling.apply(edge).unwrap()));
+    }
+}
+
+#[test]
+#[should_panic]
+fn permuting_with_a_repeated_label_panics() {
+    Graph::complete_graph(3, 2).permute(&[0, 1, 1], &[0, 1]);
+}
+
+#[test]
+fn union_of_a_graph_and_its_transpose_splits_into_induced_subgraphs() {
+    let sampler = Sampler::builder()
+        .number_of_variables(8)
+        .number_of_constraints(6)
+        .variable_degree(3)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut thread_rng());
+    let transpose = graph.transpose();
+    assert_eq!(transpose.is_biregular(), Some((4, 3)));
+
+    let union = graph.disjoint_union(&transpose);
+    assert_eq!(union.number_of_variables(), 14);
+    assert_eq!(union.number_of_constraints(), 14);
+    assert_eq!(union.validate(), Ok(()));
+
+    let variables: Vec<usize> = (8..14).collect();
+    let constraints: Vec<usize> = (6..14).collect();
+    let (subgraph, _) = union.induced_subgraph(&variables, &constraints);
+    assert_eq!(subgraph, transpose);
+
+    let (mixed, relabeling) = union.induced_subgraph(&[0, 8], &[0, 6]);
+    assert_eq!(
+        mixed.number_of_edges(),
+        union
+            .edges()
+            .filter_map(|edge| relabeling.apply(edge))
+            .count()
+    );
+    assert!(!mixed.contains_edge(Edge::new(0, 1)));
+    assert!(!mixed.contains_edge(Edge::new(1, 0)));
+}
+
+#[test]
+fn null_space_has_the_dimension_of_the_code() {
+    let sampler = Sampler::builder()
+        .number_of_variables(30)
+        .number_of_constraints(20)
+        .variable_degree(2)
+        .constraint_degree(3)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let graph = sampler.sample_with(&mut thread_rng());
+        let rank = graph.rank();
+        // Each variable is in exactly two constraints, so the sum of all constraints is zero.
+        assert!(rank < 20);
+        assert_eq!(graph.redundant_constraints().len(), 20 - rank);
+        assert_eq!(graph.dimension(), 30 - rank);
+
+        let basis = graph.null_space();
+        assert_eq!(basis.number_of_rows(), graph.dimension());
+        assert_eq!(basis.rank(), graph.dimension());
+        for codeword in 0..basis.number_of_rows() {
+            for constraint in graph.constraints() {
+                let parity = constraint
+                    .neighbors()
+                    .iter()
+                    .filter(|&&variable| basis.get(codeword, variable))
+                    .count();
+                assert_eq!(parity % 2, 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn encoding_is_linear_and_systematic() {
+    let sampler = Sampler::builder()
+        .number_of_variables(80)
+        .number_of_constraints(40)
+        .variable_degree(3)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let code = Code::new(sampler.sample_with(&mut thread_rng()));
+        assert_eq!(code.dimension(), code.graph().dimension());
+        assert!(code.dimension() >= 40);
+
+        let first = BitVec::random_with(code.dimension(), &mut thread_rng());
+        let second = BitVec::random_with(code.dimension(), &mut thread_rng());
+        let mut sum = first.clone();
+        sum ^= &second;
+
+        let mut encoded_sum = code.encode(&first);
+        encoded_sum ^= &code.encode(&second);
+        assert_eq!(code.encode(&sum), encoded_sum);
+        assert!(code.is_codeword(&encoded_sum));
+        assert_eq!(code.extract_message(&encoded_sum), sum);
+    }
+}
+
+#[test]
+fn syndromes_agree_and_are_linear() {
+    let sampler = Sampler::builder()
+        .number_of_variables(150)
+        .number_of_constraints(100)
+        .variable_degree(4)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let code = Code::new(sampler.sample_with(&mut thread_rng()));
+        let graph = code.graph();
+        let sparse = graph.sparse_parity_check_matrix();
+        let dense = graph.parity_check_matrix();
+
+        let error = BitVec::random_with(150, &mut thread_rng());
+        let syndrome = graph.syndrome(&error);
+        assert_eq!(syndrome.len(), 100);
+        assert_eq!(sparse.syndrome(&error), syndrome);
+        assert_eq!(dense.syndrome(&error), syndrome);
+
+        let message = BitVec::random_with(code.dimension(), &mut thread_rng());
+        let mut received = code.encode(&message);
+        assert!(sparse.syndrome(&received).is_zero());
+        received ^= &error;
+        assert_eq!(sparse.syndrome(&received), syndrome);
+    }
+}
+
+#[test]
+fn belief_propagation_corrects_few_errors() {
+    let mut rng = SmallRng::seed_from_u64(45);
+    let sampler = Sampler::builder()
+        .number_of_variables(500)
+        .number_of_constraints(250)
+        .variable_degree(3)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut rng);
+    let mut sum_product = BeliefPropagation::new(&graph);
+    let mut min_sum = BeliefPropagation::new(&graph);
+    min_sum.update_rule(UpdateRule::NormalizedMinSum(0.8));
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let mut received = BitVec::zeros(500);
+        for _ in 0..5 {
+            received.set(rng.gen_range(0..500), true);
+        }
+        let llrs = bsc_llrs(&received, 0.01);
+        for decoder in [&mut sum_product, &mut min_sum] {
+            let decoding = decoder.decode(&llrs);
+            assert!(decoding.converged);
+            assert!(decoding.word.is_zero());
+        }
+
+        // BPSK over a Gaussian channel with a standard deviation of 0.5.
+        let signal: Vec<f64> = (0..500)
+            .map(|_| {
+                let (first, second): (f64, f64) = (rng.gen(), rng.gen());
+                1.0 + 0.5 * (-2.0 * (1.0 - first).ln()).sqrt() * (2.0 * PI * second).cos()
+            })
+            .collect();
+        let decoding = sum_product.decode(&awgn_llrs(&signal, 0.5));
+        assert!(decoding.converged);
+        assert!(decoding.word.is_zero());
+    }
+}
+
+#[test]
+fn belief_propagation_without_early_stopping_uses_all_iterations() {
+    let graph = Graph::complete_graph(4, 1);
+    let mut decoder = BeliefPropagation::new(&graph);
+    decoder.max_iterations(7).early_stopping(false);
+    let decoding = decoder.decode(&[1.0, 1.0, 1.0, f64::NEG_INFINITY]);
+    assert_eq!(decoding.iterations, 7);
+    assert!(!decoding.converged);
+}
+
+#[test]
+fn flip_decoders_correct_few_errors_on_expanders() {
+    let mut rng = SmallRng::seed_from_u64(46);
+    let sampler = Sampler::builder()
+        .number_of_variables(600)
+        .number_of_constraints(300)
+        .variable_degree(5)
+        .constraint_degree(10)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut rng);
+    let mut bit_flipping = BitFlipping::new(&graph);
+    let mut sipser_spielman = SipserSpielman::new(&graph);
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let mut received = BitVec::zeros(600);
+        for _ in 0..4 {
+            received.set(rng.gen_range(0..600), true);
+        }
+        let llrs = bsc_llrs(&received, 0.01);
+        let decoders: [&mut dyn Decoder; 2] = [&mut bit_flipping, &mut sipser_spielman];
+        for decoder in decoders {
+            let decoding = decoder.decode(&llrs);
+            assert!(decoding.converged);
+            assert!(decoding.word.is_zero());
+        }
+        let decoding = sipser_spielman.decode(&llrs);
+        assert_eq!(decoding.iterations, received.weight());
+    }
+}
+
+#[test]
+fn simulations_do_not_depend_on_the_number_of_threads() {
+    let sampler = Sampler::builder()
+        .number_of_variables(60)
+        .number_of_constraints(30)
+        .variable_degree(3)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut SmallRng::seed_from_u64(47));
+    for channel in [
+        Channel::BinarySymmetric(0.05),
+        Channel::BinaryErasure(0.4),
+        Channel::Awgn(0.9),
+    ] {
+        let mut simulation = Simulation::new(&graph, channel);
+        simulation.max_trials(500).target_frame_errors(20).seed(7);
+        let rates = simulation.number_of_threads(1).run(BeliefPropagation::new);
+        assert_eq!(
+            simulation.number_of_threads(3).run(BeliefPropagation::new),
+            rates
+        );
+        assert!(rates.frame_errors <= 20);
+        assert!(rates.trials == 500 || rates.frame_errors == 20);
+        assert!(rates.bit_errors >= rates.frame_errors);
+        assert!(rates.bit_error_rate() <= rates.frame_error_rate());
+    }
+}
+
+#[test]
+fn unrecovered_erasures_are_errors() {
+    // A single parity check can't recover two erasures.
+    let graph = Graph::complete_graph(3, 1);
+    let rates = Simulation::new(&graph, Channel::BinaryErasure(1.0))
+        .max_trials(10)
+        .run(BeliefPropagation::new);
+    assert_eq!(rates.trials, 10);
+    assert_eq!(rates.frame_errors, 10);
+    assert_eq!(rates.bit_error_rate(), 1.0);
+
+    // Undetermined variables are reported instead of being decoded as 1.
+    let decoding = BeliefPropagation::new(&graph).decode(&[0.0; 3]);
+    assert!(decoding.word.is_zero());
+    assert_eq!(decoding.undetermined.weight(), 3);
+    assert!(!decoding.converged);
+    assert_eq!(decoding.iterations, 50);
+}
+
+#[test]
+fn belief_propagation_recovers_erasures_like_peeling() {
+    let sampler = Sampler::builder()
+        .number_of_variables(500)
+        .number_of_constraints(250)
+        .variable_degree(3)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let graph = sampler.sample_with(&mut thread_rng());
+        let mut erased = BitVec::zeros(500);
+        for _ in 0..3 {
+            erased.set(thread_rng().gen_range(0..500), true);
+        }
+        let llrs: Vec<f64> = erased
+            .iter()
+            .map(|is_erased| if is_erased { 0.0 } else { f64::INFINITY })
+            .collect();
+        let decoding = BeliefPropagation::new(&graph).decode(&llrs);
+        let remaining = graph.peel_erasures(&erased);
+        assert!(decoding.iterations > 0);
+        assert!(decoding.word.is_zero());
+        assert_eq!(decoding.undetermined, remaining);
+        assert_eq!(decoding.converged, remaining.is_zero());
+    }
+}
+
+#[test]
+fn erasures_below_the_threshold_are_recovered() {
+    let sampler = Sampler::builder()
+        .number_of_variables(500)
+        .number_of_constraints(250)
+        .variable_degree(3)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    let graph = sampler.sample_with(&mut SmallRng::seed_from_u64(47));
+    let mut simulation = Simulation::new(&graph, Channel::BinaryErasure(0.1));
+    simulation.max_trials(100).seed(7);
+    for rates in [
+        simulation.run(BeliefPropagation::new),
+        simulation.run(Peeling::new),
+    ] {
+        assert_eq!(rates.trials, 100);
+        assert!(rates.frame_error_rate() < 0.05);
+    }
+}
+
+#[test]
+fn peeling_stops_on_stopping_sets() {
+    let sampler = Sampler::builder()
+        .number_of_variables(200)
+        .number_of_constraints(100)
+        .variable_degree(3)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let graph = sampler.sample_with(&mut thread_rng());
+        let erased: BitVec = (0..200).map(|_| thread_rng().gen_bool(0.45)).collect();
+        let remaining = graph.peel_erasures(&erased);
+        for variable in remaining.ones() {
+            assert!(erased.get(variable));
+        }
+        for constraint in graph.constraints() {
+            let number_of_remaining = constraint
+                .neighbors()
+                .iter()
+                .filter(|&&variable| remaining.get(variable))
+                .count();
+            assert_ne!(number_of_remaining, 1);
+        }
+
+        let llrs: Vec<f64> = erased
+            .iter()
+            .map(|is_erased| if is_erased { 0.0 } else { f64::INFINITY })
+            .collect();
+        let decoding = Peeling::new(&graph).decode(&llrs);
+        assert!(decoding.word.is_zero());
+        assert_eq!(decoding.undetermined, remaining);
+        assert_eq!(decoding.converged, remaining.is_zero());
+        assert_eq!(decoding.iterations, erased.weight() - remaining.weight());
+    }
+}
+
+#[test]
+fn two_core_has_no_leaves() {
+    let sampler = Sampler::builder()
+        .number_of_variables(100)
+        .number_of_constraints(50)
+        .variable_degree(2)
+        .constraint_degree(4)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let mut graph = sampler.sample_with(&mut thread_rng());
+        for _ in 0..30 {
+            let edge = graph.edges().next().unwrap();
+            graph.remove_edge(edge);
+        }
+        let (core, relabeling) = graph.two_core();
+        assert!(core.variables().all(|variable| variable.degree() >= 2));
+        assert!(core
+            .constraints()
+            .all(|constraint| constraint.degree() >= 1));
+        for edge in core.edges() {
+            let variable = relabeling
+                .variables
+                .iter()
+                .position(|&v| v == Some(edge.variable));
+            let constraint = relabeling
+                .constraints
+                .iter()
+                .position(|&c| c == Some(edge.constraint));
+            assert!(graph.contains_edge(Edge::new(variable.unwrap(), constraint.unwrap())));
+        }
+        let (core_of_core, _) = core.two_core();
+        assert_eq!(core_of_core, core);
+    }
+}
+
+#[test]
+fn thresholds_decrease_with_the_rate() {
+    let sampler = |variable_degree, constraint_degree| {
+        Sampler::builder()
+            .number_of_variables(constraint_degree)
+            .number_of_constraints(variable_degree)
+            .variable_degree(variable_degree)
+            .constraint_degree(constraint_degree)
+            .build()
+            .unwrap()
+    };
+    // Known values for the (3, 4), (3, 6) and (4, 8) ensembles.
+    assert!((sampler(3, 4).bec_threshold() - 0.6474).abs() < 1e-3);
+    assert!((sampler(3, 6).bec_threshold() - 0.4294).abs() < 1e-3);
+    assert!((sampler(4, 8).bec_threshold() - 0.3834).abs() < 1e-3);
+    assert!(sampler(3, 4).awgn_threshold() > sampler(3, 6).awgn_threshold());
+    assert!(sampler(3, 6).awgn_threshold() > sampler(3, 9).awgn_threshold());
+    assert_eq!(sampler(1, 2).bec_threshold(), 0.0);
+
+    // The threshold is below the capacity of the erasure channel.
+    assert!(sampler(3, 6).bec_threshold() < 0.5);
+}
+
+#[test]
+fn minimum_distance_is_below_its_upper_bound() {
+    let sampler = Sampler::builder()
+        .number_of_variables(24)
+        .number_of_constraints(12)
+        .variable_degree(3)
+        .constraint_degree(6)
+        .build()
+        .unwrap();
+    for _ in 0..NUMBER_OF_RANDOM_TESTS {
+        let code = Code::new(sampler.sample_with(&mut thread_rng()));
+        let distance = code.minimum_distance().unwrap();
+        let upper_bound = code
+            .minimum_distance_upper_bound_with(5, &mut thread_rng())
+            .unwrap();
+        assert!(distance >= 2);
+        assert!(distance <= upper_bound);
+        // The Singleton bound.
+        assert!(distance <= 24 - code.dimension() + 1);
+    }
+    assert_eq!(
+        Code::new(Graph::complete_graph(3, 3)).minimum_distance(),
+        Some(2)
+    );
+    assert_eq!(
+        Code::new(Graph::from_variable_neighbors(vec![vec![0]])).minimum_distance(),
+        None
+    );
+}
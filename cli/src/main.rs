@@ -4,6 +4,7 @@ use bigs::Sampler;
 use rand::{thread_rng, Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use serde::Serialize;
+use std::io::Write;
 use std::path::PathBuf;
 use structopt::StructOpt;
 
@@ -57,6 +58,38 @@ struct Options {
     /// it will be printed to the standard output.
     #[structopt(short = "o", long = "output", parse(from_os_str), name = "output path")]
     output_path: Option<PathBuf>,
+
+    /// The format of the output. Either json, mtx (Matrix Market), npy (dense NumPy array)
+    /// or npz (SciPy sparse matrix). Only json includes the parameters and the seed.
+    /// The other formats contain the parity-check matrix of the graph
+    /// with constraints as rows and variables as columns.
+    #[structopt(short = "f", long = "format", default_value = "json", name = "format")]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Json,
+    MatrixMarket,
+    Npy,
+    Npz,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "json" => Ok(Self::Json),
+            "mtx" => Ok(Self::MatrixMarket),
+            "npy" => Ok(Self::Npy),
+            "npz" => Ok(Self::Npz),
+            _ => Err(format!(
+                "unknown format {}, expected json, mtx, npy or npz",
+                format
+            )),
+        }
+    }
 }
 
 fn main() {
@@ -98,6 +131,9 @@ fn rng(options: &mut Options) -> ChaCha20Rng {
 }
 
 fn save_or_display(graph: Graph, options: Options) {
+    if options.format != Format::Json {
+        return save_or_display_matrix(graph, options);
+    }
     let output = Output {
         number_of_variables: graph.number_of_variables(),
         number_of_constraints: graph.number_of_constraints(),
@@ -121,6 +157,28 @@ fn save_or_display(graph: Graph, options: Options) {
     }
 }
 
+fn save_or_display_matrix(graph: Graph, options: Options) {
+    let mut bytes = Vec::new();
+    let result = match options.format {
+        Format::MatrixMarket => graph.write_matrix_market(&mut bytes),
+        Format::Npy => graph.write_npy(&mut bytes),
+        Format::Npz => graph.write_npz(&mut bytes),
+        Format::Json => unreachable!("json output includes the parameters"),
+    };
+    if let Err(e) = result {
+        println!("Failed to convert to {:?}: {}", options.format, e);
+        return;
+    }
+    if let Some(path) = options.output_path {
+        match std::fs::write(&path, bytes) {
+            Ok(_) => println!("Saved output to {}", path.to_string_lossy()),
+            Err(e) => println!("Error while saving: {}", e),
+        }
+    } else if let Err(e) = std::io::stdout().write_all(&bytes) {
+        println!("Error while writing: {}", e);
+    }
+}
+
 #[derive(Serialize)]
 struct Output {
     number_of_variables: usize,